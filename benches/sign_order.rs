@@ -0,0 +1,83 @@
+//! Benchmarks for the hot-path limit order signing flow
+//!
+//! Measures [`HotPathClient::sign_limit_order`] end to end, excluding network: validation,
+//! amount conversion, salt generation, EIP-712 hashing and the secp256k1 signature.
+//!
+//! The per-order signing budget is 100 µs on a single modern x86-64 core; criterion reports the
+//! measured median for each case below, and a regression past the budget should be treated as a
+//! bug in the signing path. The iterations run on a reused tokio runtime through
+//! [`criterion::Bencher::to_async`], so runtime setup is not part of the figure.
+//!
+//! Run with `cargo bench --bench sign_order --features clob`.
+
+use std::str::FromStr as _;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use polymarket_client_sdk::POLYGON;
+use polymarket_client_sdk::auth::{Credentials, SecretString, Uuid};
+use polymarket_client_sdk::clob::types::{Side, SignatureType, TickSize};
+use polymarket_client_sdk::hotpath::{
+    HotPathClient, HotPathConfig, HotPathPolicies, LimitOrderOverrides, LimitOrderRequest,
+};
+use polymarket_client_sdk::types::{Address, U256};
+use rust_decimal_macros::dec;
+use url::Url;
+
+const TOKEN_ID: &str =
+    "15871154585880608648532107628464183779895785213830018178010423617714102767076";
+
+// Dummy private key for benchmarking (DO NOT USE IN PRODUCTION)
+const BENCH_PRIVATE_KEY: &str =
+    "0x0000000000000000000000000000000000000000000000000000000000000001";
+
+/// Helper to create a hot-path client with fixed policies, so signing never touches the network
+fn setup_client() -> HotPathClient {
+    let policies = HotPathPolicies::fixed(TickSize::Hundredth, false, 0);
+    let config = HotPathConfig::new(
+        Url::parse("https://clob.polymarket.com").expect("valid host"),
+        POLYGON,
+        SecretString::from(BENCH_PRIVATE_KEY.to_owned()),
+        SignatureType::Proxy,
+        Address::repeat_byte(0x11),
+        None,
+        policies,
+    )
+    .expect("valid config");
+    let credentials = Credentials::new(Uuid::nil(), String::new(), String::new());
+
+    HotPathClient::with_credentials(config, credentials).expect("client builds")
+}
+
+/// Benchmark limit order signing for both exchanges and sides
+fn bench_sign_limit_order(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    let client = setup_client();
+    let token_id = U256::from_str(TOKEN_ID).expect("valid token ID");
+
+    let mut group = c.benchmark_group("sign_order/sign_limit_order");
+
+    for (name, side, neg_risk) in [
+        ("BUY", Side::Buy, false),
+        ("SELL", Side::Sell, false),
+        ("BUY_neg_risk", Side::Buy, true),
+    ] {
+        let request = LimitOrderRequest::new(token_id, side, dec!(0.50), dec!(100));
+        let overrides = LimitOrderOverrides::default().with_neg_risk(neg_risk);
+
+        group.bench_function(name, |b| {
+            b.to_async(&runtime).iter(|| async {
+                std::hint::black_box(
+                    client
+                        .sign_limit_order(&request, overrides)
+                        .await
+                        .expect("sign succeeds"),
+                )
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(sign_order_benches, bench_sign_limit_order);
+criterion_main!(sign_order_benches);
@@ -18,11 +18,19 @@ use sha2::Sha256;
 /// UUID type used for API keys and identifiers.
 pub use uuid::Uuid;
 
+use crate::error::Error;
 use crate::{Result, Timestamp};
 
 /// Type alias for API keys, which are UUIDs.
 pub type ApiKey = Uuid;
 
+/// Environment variable read by [`Credentials::from_env`] for the API key.
+pub const API_KEY_VAR: &str = "CLOB_API_KEY";
+/// Environment variable read by [`Credentials::from_env`] for the secret.
+pub const SECRET_VAR: &str = "CLOB_SECRET";
+/// Environment variable read by [`Credentials::from_env`] for the passphrase.
+pub const PASSPHRASE_VAR: &str = "CLOB_PASSPHRASE";
+
 /// Generic set of credentials used to authenticate to the Polymarket API. These credentials are
 /// returned when calling [`crate::clob::Client::create_or_derive_api_key`], [`crate::clob::Client::derive_api_key`], or
 /// [`crate::clob::Client::create_api_key`]. They are used by the [`state::Authenticated`] client to
@@ -45,12 +53,35 @@ impl Credentials {
         }
     }
 
+    /// Reads credentials from [`API_KEY_VAR`], [`SECRET_VAR`] and [`PASSPHRASE_VAR`], without
+    /// calling the auth endpoints.
+    ///
+    /// The error names every variable that is missing or empty.
+    pub fn from_env() -> Result<Self> {
+        Self::from_lookup(|name| std::env::var(name).ok())
+    }
+
+    pub(crate) fn from_lookup<F: Fn(&str) -> Option<String>>(lookup: F) -> Result<Self> {
+        let [key, secret, passphrase] =
+            crate::required_vars([API_KEY_VAR, SECRET_VAR, PASSPHRASE_VAR], lookup)?;
+        let key = Uuid::parse_str(&key)
+            .map_err(|e| Error::validation(format!("invalid {API_KEY_VAR}: {e}")))?;
+
+        Ok(Self::new(key, secret, passphrase))
+    }
+
     /// Returns the API key.
     #[must_use]
     pub fn key(&self) -> ApiKey {
         self.key
     }
 
+    /// Returns the API key sent in the `POLY_API_KEY` header; the same value as [`Self::key`].
+    #[must_use]
+    pub fn api_key(&self) -> ApiKey {
+        self.key
+    }
+
     /// Returns the secret.
     #[must_use]
     pub fn secret(&self) -> &SecretString {
@@ -58,6 +89,9 @@ impl Credentials {
     }
 
     /// Returns the passphrase.
+    ///
+    /// Unlike the secret, the passphrase is sent as-is in the `POLY_PASSPHRASE` header, so it
+    /// can be compared against a failing request when debugging L2 auth.
     #[must_use]
     pub fn passphrase(&self) -> &SecretString {
         &self.passphrase
@@ -435,6 +469,7 @@ mod tests {
     use crate::auth::builder::Config;
     #[cfg(feature = "clob")]
     use crate::auth::state::Authenticated;
+    use crate::tests::env;
     #[cfg(feature = "clob")]
     use crate::types::address;
     #[cfg(feature = "clob")]
@@ -444,6 +479,70 @@ mod tests {
     #[cfg(feature = "clob")]
     const PRIVATE_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
 
+    #[test]
+    fn credentials_from_env_should_read_all_parts() -> Result<()> {
+        let credentials = Credentials::from_lookup(env(&[
+            (API_KEY_VAR, "ffffffff-ffff-ffff-ffff-ffffffffffff"),
+            (SECRET_VAR, "my-secret"),
+            (PASSPHRASE_VAR, "my-passphrase"),
+        ]))?;
+
+        assert_eq!(credentials.key(), Uuid::max());
+        assert_eq!(credentials.secret().expose_secret(), "my-secret");
+        assert_eq!(credentials.passphrase().expose_secret(), "my-passphrase");
+
+        Ok(())
+    }
+
+    #[test]
+    fn credentials_from_env_should_name_missing_variables() {
+        let err = Credentials::from_lookup(env(&[
+            (API_KEY_VAR, "ffffffff-ffff-ffff-ffff-ffffffffffff"),
+            (SECRET_VAR, ""),
+        ]))
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Validation: invalid: missing environment variables: CLOB_SECRET, CLOB_PASSPHRASE"
+        );
+    }
+
+    #[test]
+    fn credentials_from_env_should_reject_invalid_api_key() {
+        let err = Credentials::from_lookup(env(&[
+            (API_KEY_VAR, "not-a-uuid"),
+            (SECRET_VAR, "my-secret"),
+            (PASSPHRASE_VAR, "my-passphrase"),
+        ]))
+        .unwrap_err();
+
+        assert!(
+            err.to_string().contains("invalid CLOB_API_KEY"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn credentials_accessors_should_return_non_secret_parts() {
+        let credentials = Credentials::new(
+            Uuid::max(),
+            "my-secret".to_owned(),
+            "my-passphrase".to_owned(),
+        );
+
+        assert_eq!(credentials.key(), Uuid::max());
+        assert_eq!(credentials.api_key(), Uuid::max());
+        assert_eq!(credentials.passphrase().expose_secret(), "my-passphrase");
+
+        let debug = format!("{credentials:?}");
+        assert!(!debug.contains("my-secret"), "secret should be redacted");
+        assert!(
+            !debug.contains("my-passphrase"),
+            "passphrase should be redacted"
+        );
+    }
+
     #[cfg(feature = "clob")]
     #[tokio::test]
     async fn l1_headers_should_succeed() -> anyhow::Result<()> {
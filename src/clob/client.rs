@@ -1,11 +1,9 @@
-use std::borrow::Cow;
 use std::marker::PhantomData;
 use std::mem;
 use std::sync::Arc;
 #[cfg(feature = "heartbeats")]
 use std::time::Duration;
 
-use alloy::dyn_abi::Eip712Domain;
 use alloy::primitives::U256;
 use alloy::signers::Signer;
 use alloy::sol_types::SolStruct as _;
@@ -52,17 +50,14 @@ use crate::clob::types::{
     CreateRfqRequestRequest, CreateRfqRequestResponse, RfqQuote, RfqQuotesRequest, RfqRequest,
     RfqRequestsRequest,
 };
-use crate::clob::types::{SignableOrder, SignatureType, SignedOrder, TickSize};
+use crate::clob::types::{SignableOrder, SignatureType, SignedOrder, TickSize, order_domain};
 use crate::error::{Error, Kind as ErrorKind, Synchronization};
 use crate::types::Address;
 use crate::{
-    AMOY, POLYGON, Result, Timestamp, ToQueryParams as _, auth, contract_config,
-    derive_proxy_wallet, derive_safe_wallet,
+    AMOY, POLYGON, Result, Timestamp, ToQueryParams as _, auth, derive_proxy_wallet,
+    derive_safe_wallet,
 };
 
-const ORDER_NAME: Option<Cow<'static, str>> = Some(Cow::Borrowed("Polymarket CTF Exchange"));
-const VERSION: Option<Cow<'static, str>> = Some(Cow::Borrowed("1"));
-
 const TERMINAL_CURSOR: &str = "LTE="; // base64("-1")
 
 /// The type used to build a request to authenticate the inner [`Client<Unauthorized>`]. Calling
@@ -1442,17 +1437,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .chain_id()
             .expect("Validated not none in `authenticate`");
 
-        let exchange_contract = contract_config(chain_id, neg_risk)
-            .ok_or(Error::missing_contract_config(chain_id, neg_risk))?
-            .exchange;
-
-        let domain = Eip712Domain {
-            name: ORDER_NAME,
-            version: VERSION,
-            chain_id: Some(U256::from(chain_id)),
-            verifying_contract: Some(exchange_contract),
-            ..Eip712Domain::default()
-        };
+        let domain = order_domain(chain_id, neg_risk)?;
 
         let signature = signer
             .sign_hash(&order.eip712_signing_hash(&domain))
@@ -1,7 +1,10 @@
+use std::borrow::Cow;
 use std::fmt;
 
 use alloy::core::sol;
+use alloy::dyn_abi::Eip712Domain;
 use alloy::primitives::{Signature, U256};
+use alloy::sol_types::SolStruct as _;
 use bon::Builder;
 use rust_decimal_macros::dec;
 use serde::ser::{Error as _, SerializeStruct as _};
@@ -13,8 +16,9 @@ use strum_macros::Display;
 use crate::Result;
 use crate::auth::ApiKey;
 use crate::clob::order_builder::{LOT_SIZE_SCALE, USDC_DECIMALS};
+use crate::contract_config;
 use crate::error::Error;
-use crate::types::Decimal;
+use crate::types::{Address, ChainId, Decimal};
 
 pub mod request;
 pub mod response;
@@ -294,8 +298,13 @@ pub enum OrderStatusType {
     Matched,
     #[serde(alias = "canceled")]
     Canceled,
+    /// Accepted, but matching is deferred (e.g. in sports markets with a matching delay).
+    ///
+    /// The order is neither rejected nor filled yet; it moves on to `Matched` or `Unmatched`
+    /// once the delay elapses.
     #[serde(alias = "delayed")]
     Delayed,
+    /// A delayed order that found no match and was not placed on the book.
     #[serde(alias = "unmatched")]
     Unmatched,
     /// Unknown order status type from the API (captures the raw value for debugging).
@@ -382,6 +391,58 @@ impl TickSize {
             TickSize::TenThousandth => dec!(0.0001),
         }
     }
+
+    /// Parses a tick size given as a decimal string, such as `"0.01"`, as reported by the
+    /// CLOB and other systems that don't know the enum.
+    pub fn try_from_decimal_str(value: &str) -> Result<Self> {
+        let decimal = value
+            .trim()
+            .parse::<Decimal>()
+            .map_err(|e| Error::validation(format!("invalid tick size `{value}`: {e}")))?;
+        Self::try_from(decimal)
+    }
+
+    /// Moves `price` one tick toward the aggressive side: up for [`Side::Buy`], down for
+    /// [`Side::Sell`].
+    ///
+    /// An off-grid `price` snaps to the next tick in that direction. The result is clamped to
+    /// the tradeable range `[tick, 1 - tick]`; [`Side::Unknown`] only clamps.
+    #[must_use]
+    pub fn increment(&self, price: Decimal, side: Side) -> Decimal {
+        match side {
+            Side::Buy => self.clamp(self.step_up(price)),
+            Side::Sell => self.clamp(self.step_down(price)),
+            Side::Unknown => self.clamp(price),
+        }
+    }
+
+    /// Moves `price` one tick toward the passive side: down for [`Side::Buy`], up for
+    /// [`Side::Sell`].
+    ///
+    /// Snapping and clamping follow [`Self::increment`].
+    #[must_use]
+    pub fn decrement(&self, price: Decimal, side: Side) -> Decimal {
+        match side {
+            Side::Buy => self.clamp(self.step_down(price)),
+            Side::Sell => self.clamp(self.step_up(price)),
+            Side::Unknown => self.clamp(price),
+        }
+    }
+
+    fn step_up(self, price: Decimal) -> Decimal {
+        let tick = self.as_decimal();
+        (price / tick).floor() * tick + tick
+    }
+
+    fn step_down(self, price: Decimal) -> Decimal {
+        let tick = self.as_decimal();
+        (price / tick).ceil() * tick - tick
+    }
+
+    fn clamp(self, price: Decimal) -> Decimal {
+        let tick = self.as_decimal();
+        price.clamp(tick, Decimal::ONE - tick)
+    }
 }
 
 impl From<TickSize> for Decimal {
@@ -473,6 +534,10 @@ pub struct SignableOrder {
     pub post_only: Option<bool>,
 }
 
+/// An [`Order`] together with its EIP-712 signature, ready to post.
+///
+/// Orders signed out-of-band, e.g. on a hardware wallet, can be assembled with
+/// [`SignedOrder::builder`] from the order, the raw signature and the API key owner.
 #[non_exhaustive]
 #[derive(Debug, Builder, PartialEq)]
 pub struct SignedOrder {
@@ -483,6 +548,93 @@ pub struct SignedOrder {
     pub post_only: Option<bool>,
 }
 
+/// EIP-712 domain name of the CTF exchanges.
+pub(crate) const ORDER_NAME: Option<Cow<'static, str>> =
+    Some(Cow::Borrowed("Polymarket CTF Exchange"));
+/// EIP-712 domain version of the CTF exchanges.
+pub(crate) const ORDER_VERSION: Option<Cow<'static, str>> = Some(Cow::Borrowed("1"));
+
+/// Builds the EIP-712 domain orders are signed against for the exchange selected by
+/// `neg_risk` on `chain_id`.
+///
+/// Neg-risk orders are verified by the neg-risk exchange itself; the neg-risk adapter only
+/// handles splits, merges and conversions and never appears as the verifying contract.
+pub(crate) fn order_domain(chain_id: ChainId, neg_risk: bool) -> Result<Eip712Domain> {
+    let exchange = contract_config(chain_id, neg_risk)
+        .ok_or(Error::missing_contract_config(chain_id, neg_risk))?
+        .exchange;
+
+    Ok(Eip712Domain {
+        name: ORDER_NAME,
+        version: ORDER_VERSION,
+        chain_id: Some(U256::from(chain_id)),
+        verifying_contract: Some(exchange),
+        ..Eip712Domain::default()
+    })
+}
+
+impl SignedOrder {
+    /// Recovers the address that produced [`Self::signature`] over the order, hashed against
+    /// the exchange domain selected by `neg_risk` on `chain_id`.
+    ///
+    /// A result different from `order.signer` means the order was signed with another key
+    /// or for another exchange domain, and the exchange will reject it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no exchange is configured for `chain_id` and `neg_risk`, or the
+    /// signature is malformed.
+    pub fn recover_signer(&self, neg_risk: bool, chain_id: ChainId) -> Result<Address> {
+        let domain = order_domain(chain_id, neg_risk)?;
+
+        self.signature
+            .recover_address_from_prehash(&self.order.eip712_signing_hash(&domain))
+            .map_err(|e| Error::validation(format!("Unable to recover order signer: {e}")))
+    }
+
+    /// Returns the fee the order incurs if it fills completely, in the asset it receives:
+    /// shares for a BUY and USDC for a SELL.
+    ///
+    /// Follows the exchange's `CalculatorHelper.calculateFee` in raw 6-decimal units,
+    /// including its integer truncation: a SELL pays
+    /// `fee_rate_bps / 10_000 * min(price, 1 - price) * shares` and a BUY pays the same amount
+    /// divided by `price`, so the fee is reserved in shares rather than collateral.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `side` is not a valid [`Side`] or the fee doesn't fit a
+    /// [`Decimal`].
+    pub fn max_fee(&self) -> Result<Decimal> {
+        const ONE: U256 = U256::from_limbs([1_000_000_000_000_000_000, 0, 0, 0]);
+        const BPS_DIVISOR: U256 = U256::from_limbs([10_000, 0, 0, 0]);
+
+        let order = &self.order;
+        let side = Side::try_from(order.side)?;
+        let (collateral, shares) = match side {
+            Side::Buy => (order.makerAmount, order.takerAmount),
+            _ => (order.takerAmount, order.makerAmount),
+        };
+        if shares.is_zero() {
+            return Ok(Decimal::ZERO);
+        }
+
+        let price = collateral * ONE / shares;
+        if price.is_zero() || price > ONE {
+            return Ok(Decimal::ZERO);
+        }
+        let weight = order.feeRateBps * price.min(ONE - price) * shares;
+        let fee = match side {
+            Side::Buy => weight / (price * BPS_DIVISOR),
+            _ => weight / (BPS_DIVISOR * ONE),
+        };
+
+        let fee = i128::try_from(fee)
+            .map_err(|_e| Error::validation(format!("fee {fee} does not fit a Decimal")))?;
+        Decimal::try_from_i128_with_scale(fee, USDC_DECIMALS)
+            .map_err(|e| Error::validation(format!("fee {fee} does not fit a Decimal: {e}")))
+    }
+}
+
 /// Helper struct for serializing Order with signature injected.
 /// This avoids the overhead of `serde_json::to_value()` followed by mutation.
 #[serde_as]
@@ -554,11 +706,85 @@ impl Serialize for SignedOrder {
     }
 }
 
+/// Owned counterpart of [`OrderWithSignature`], used to read back a serialized [`SignedOrder`].
+#[serde_as]
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OwnedOrderWithSignature {
+    salt: u64,
+    maker: alloy::primitives::Address,
+    signer: alloy::primitives::Address,
+    taker: alloy::primitives::Address,
+    #[serde_as(as = "DisplayFromStr")]
+    token_id: U256,
+    #[serde_as(as = "DisplayFromStr")]
+    maker_amount: U256,
+    #[serde_as(as = "DisplayFromStr")]
+    taker_amount: U256,
+    #[serde_as(as = "DisplayFromStr")]
+    expiration: U256,
+    #[serde_as(as = "DisplayFromStr")]
+    nonce: U256,
+    #[serde_as(as = "DisplayFromStr")]
+    fee_rate_bps: U256,
+    side: Side,
+    signature_type: u8,
+    #[serde_as(as = "DisplayFromStr")]
+    signature: Signature,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignedOrderBody {
+    order: OwnedOrderWithSignature,
+    order_type: OrderType,
+    owner: ApiKey,
+    post_only: Option<bool>,
+}
+
+// Inverse of the `Serialize` impl above, so a submitted body can be audited against its order
+impl<'de> Deserialize<'de> for SignedOrder {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let SignedOrderBody {
+            order,
+            order_type,
+            owner,
+            post_only,
+        } = SignedOrderBody::deserialize(deserializer)?;
+
+        Ok(Self {
+            order: Order {
+                salt: U256::from(order.salt),
+                maker: order.maker,
+                signer: order.signer,
+                taker: order.taker,
+                tokenId: order.token_id,
+                makerAmount: order.maker_amount,
+                takerAmount: order.taker_amount,
+                expiration: order.expiration,
+                nonce: order.nonce,
+                feeRateBps: order.fee_rate_bps,
+                side: order.side as u8,
+                signatureType: order.signature_type,
+            },
+            signature: order.signature,
+            order_type,
+            owner,
+            post_only,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr as _;
+
+    use alloy::signers::SignerSync as _;
+    use alloy::signers::local::PrivateKeySigner;
     use serde_json::to_value;
 
     use super::*;
+    use crate::POLYGON;
     use crate::error::Validation;
 
     #[test]
@@ -606,6 +832,102 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tick_from_decimal_str_should_succeed() {
+        assert_eq!(
+            TickSize::try_from_decimal_str("0.01").unwrap(),
+            TickSize::Hundredth
+        );
+        assert_eq!(
+            TickSize::try_from_decimal_str("0.001").unwrap(),
+            TickSize::Thousandth
+        );
+    }
+
+    #[test]
+    fn unsupported_decimal_str_to_tick_size_should_fail() {
+        let err = TickSize::try_from_decimal_str("0.05").unwrap_err();
+        assert!(
+            err.to_string().contains("Unknown tick size: 0.05"),
+            "unexpected error: {err}"
+        );
+
+        let err = TickSize::try_from_decimal_str("a cent").unwrap_err();
+        assert!(
+            err.to_string().contains("invalid tick size `a cent`"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn tick_increment_should_move_toward_aggressive_side() {
+        assert_eq!(
+            TickSize::Hundredth.increment(dec!(0.45), Side::Buy),
+            dec!(0.46)
+        );
+        assert_eq!(
+            TickSize::Hundredth.increment(dec!(0.45), Side::Sell),
+            dec!(0.44)
+        );
+        assert_eq!(TickSize::Tenth.increment(dec!(0.5), Side::Buy), dec!(0.6));
+        assert_eq!(
+            TickSize::Thousandth.increment(dec!(0.123), Side::Sell),
+            dec!(0.122)
+        );
+        assert_eq!(
+            TickSize::TenThousandth.increment(dec!(0.5), Side::Buy),
+            dec!(0.5001)
+        );
+    }
+
+    #[test]
+    fn tick_decrement_should_move_toward_passive_side() {
+        assert_eq!(
+            TickSize::Hundredth.decrement(dec!(0.45), Side::Buy),
+            dec!(0.44)
+        );
+        assert_eq!(
+            TickSize::Hundredth.decrement(dec!(0.45), Side::Sell),
+            dec!(0.46)
+        );
+        assert_eq!(TickSize::Tenth.decrement(dec!(0.5), Side::Sell), dec!(0.6));
+        assert_eq!(
+            TickSize::TenThousandth.decrement(dec!(0.5), Side::Buy),
+            dec!(0.4999)
+        );
+    }
+
+    #[test]
+    fn tick_step_should_snap_off_grid_prices() {
+        assert_eq!(
+            TickSize::Hundredth.increment(dec!(0.455), Side::Buy),
+            dec!(0.46)
+        );
+        assert_eq!(
+            TickSize::Hundredth.decrement(dec!(0.455), Side::Buy),
+            dec!(0.45)
+        );
+        assert_eq!(TickSize::Tenth.increment(dec!(0.55), Side::Sell), dec!(0.5));
+    }
+
+    #[test]
+    fn tick_step_should_clamp_to_tradeable_range() {
+        assert_eq!(
+            TickSize::Hundredth.increment(dec!(0.99), Side::Buy),
+            dec!(0.99)
+        );
+        assert_eq!(
+            TickSize::Hundredth.increment(dec!(0.01), Side::Sell),
+            dec!(0.01)
+        );
+        assert_eq!(TickSize::Tenth.decrement(dec!(0.1), Side::Buy), dec!(0.1));
+        assert_eq!(TickSize::Tenth.decrement(dec!(0.9), Side::Sell), dec!(0.9));
+        assert_eq!(
+            TickSize::Thousandth.increment(dec!(1.5), Side::Unknown),
+            dec!(0.999)
+        );
+    }
+
     #[test]
     fn amount_should_succeed() -> Result<()> {
         let usdc = Amount::usdc(Decimal::ONE_HUNDRED)?;
@@ -725,4 +1047,149 @@ mod tests {
 
         assert!(!object.contains_key("postOnly"));
     }
+
+    #[test]
+    fn signed_order_should_round_trip_through_json() {
+        let signed_order = SignedOrder {
+            order: Order {
+                salt: U256::from(479_249_096_354_u64),
+                tokenId: U256::from(1234),
+                makerAmount: U256::from(4_500_000),
+                takerAmount: U256::from(10_000_000),
+                side: Side::Sell as u8,
+                signatureType: 1,
+                ..Order::default()
+            },
+            signature: Signature::new(U256::from(1), U256::from(2), true),
+            order_type: OrderType::GTD,
+            owner: ApiKey::nil(),
+            post_only: Some(true),
+        };
+
+        let bytes = serde_json::to_vec(&signed_order).expect("serialize SignedOrder");
+        let parsed: SignedOrder = serde_json::from_slice(&bytes).expect("deserialize SignedOrder");
+
+        assert_eq!(parsed, signed_order);
+    }
+
+    fn signed(side: Side, maker_amount: u64, taker_amount: u64, fee_rate_bps: u64) -> SignedOrder {
+        SignedOrder {
+            order: Order {
+                makerAmount: U256::from(maker_amount),
+                takerAmount: U256::from(taker_amount),
+                feeRateBps: U256::from(fee_rate_bps),
+                side: side as u8,
+                ..Order::default()
+            },
+            signature: Signature::new(U256::ZERO, U256::ZERO, false),
+            order_type: OrderType::GTC,
+            owner: ApiKey::nil(),
+            post_only: None,
+        }
+    }
+
+    #[test]
+    fn max_fee_should_follow_exchange_fee_basis() -> Result<()> {
+        // BUY 100 shares at 0.40, 1%: fee in shares, min(p, 1 - p) / p = 1
+        assert_eq!(
+            signed(Side::Buy, 40_000_000, 100_000_000, 100).max_fee()?,
+            dec!(1)
+        );
+        // BUY 100 shares at 0.80, 1%: 0.01 * 0.2 / 0.8 * 100
+        assert_eq!(
+            signed(Side::Buy, 80_000_000, 100_000_000, 100).max_fee()?,
+            dec!(0.25)
+        );
+        // SELL 100 shares at 0.80, 2%: fee in USDC, 0.02 * 0.2 * 100
+        assert_eq!(
+            signed(Side::Sell, 100_000_000, 80_000_000, 200).max_fee()?,
+            dec!(0.4)
+        );
+        // SELL 50 shares at 0.30, 10%: 0.1 * 0.3 * 50
+        assert_eq!(
+            signed(Side::Sell, 50_000_000, 15_000_000, 1000).max_fee()?,
+            dec!(1.5)
+        );
+        // SELL 3 shares at 0.33, 0.5%: 0.005 * 0.33 * 3
+        assert_eq!(
+            signed(Side::Sell, 3_000_000, 990_000, 50).max_fee()?,
+            dec!(0.00495)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn max_fee_should_be_zero_without_fee_rate_or_size() -> Result<()> {
+        assert_eq!(
+            signed(Side::Buy, 45_000_000, 100_000_000, 0).max_fee()?,
+            Decimal::ZERO
+        );
+        assert_eq!(signed(Side::Sell, 0, 0, 100).max_fee()?, Decimal::ZERO);
+
+        Ok(())
+    }
+
+    fn signed_with_domain(neg_risk: bool) -> (SignedOrder, Address) {
+        let signer = PrivateKeySigner::from_str(
+            "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+        )
+        .expect("valid private key");
+        let domain = order_domain(POLYGON, neg_risk).expect("Polygon exchange is configured");
+        let order = Order {
+            salt: U256::from(479_249_096_354_u64),
+            signer: signer.address(),
+            tokenId: U256::from(1234),
+            makerAmount: U256::from(4_500_000),
+            takerAmount: U256::from(10_000_000),
+            ..Order::default()
+        };
+        let signature = signer
+            .sign_hash_sync(&order.eip712_signing_hash(&domain))
+            .expect("signing succeeds");
+
+        let signed = SignedOrder {
+            order,
+            signature,
+            order_type: OrderType::GTC,
+            owner: ApiKey::nil(),
+            post_only: None,
+        };
+        (signed, signer.address())
+    }
+
+    #[test]
+    fn recover_signer_should_return_signing_address() -> Result<()> {
+        let (standard, signer) = signed_with_domain(false);
+        let (neg_risk, _) = signed_with_domain(true);
+
+        assert_eq!(standard.recover_signer(false, POLYGON)?, signer);
+        assert_eq!(neg_risk.recover_signer(true, POLYGON)?, signer);
+
+        Ok(())
+    }
+
+    #[test]
+    fn recover_signer_should_expose_wrong_domain() -> Result<()> {
+        let (signed, signer) = signed_with_domain(true);
+
+        let recovered = signed.recover_signer(false, POLYGON)?;
+
+        assert_ne!(
+            recovered, signer,
+            "a neg-risk signature should not recover to the signer on the standard domain"
+        );
+        assert_ne!(recovered, signed.order.signer);
+
+        Ok(())
+    }
+
+    #[test]
+    fn recover_signer_without_contract_config_should_fail() {
+        let (signed, _) = signed_with_domain(false);
+
+        let err = signed.recover_signer(false, 1).unwrap_err();
+
+        assert_eq!(err.kind(), crate::error::Kind::MissingContractConfig);
+    }
 }
@@ -147,6 +147,66 @@ impl OrderBookSummaryResponse {
 
         Ok(format!("{result:x}"))
     }
+
+    /// Estimates how much size would fill before a resting order of `my_size` at `price` on
+    /// `side`.
+    ///
+    /// Counts every level priced better than `price` on the same side plus the rest of the
+    /// `price` level, assuming `my_size` is already part of that level and sits at the back of
+    /// its queue. Returns zero for [`Side::Unknown`]. Less size ahead means a higher fill
+    /// probability.
+    #[must_use]
+    pub fn queue_position(&self, price: Decimal, side: Side, my_size: Decimal) -> Decimal {
+        let levels = match side {
+            Side::Buy => &self.bids,
+            Side::Sell => &self.asks,
+            Side::Unknown => return Decimal::ZERO,
+        };
+
+        levels
+            .iter()
+            .map(|level| {
+                let is_better = if side == Side::Buy {
+                    level.price > price
+                } else {
+                    level.price < price
+                };
+
+                if is_better {
+                    level.size
+                } else if level.price == price {
+                    (level.size - my_size).max(Decimal::ZERO)
+                } else {
+                    Decimal::ZERO
+                }
+            })
+            .sum()
+    }
+
+    /// Returns the worst price an aggressive limit order on `side` needs to fill `size`
+    /// immediately against the book, or `None` when there isn't enough depth.
+    ///
+    /// A buy walks the asks from the lowest price up, a sell walks the bids from the highest
+    /// price down. Returns `None` for [`Side::Unknown`].
+    #[must_use]
+    pub fn marketable_price(&self, size: Decimal, side: Side) -> Option<Decimal> {
+        let mut levels: Vec<&OrderSummary> = match side {
+            Side::Buy => self.asks.iter().collect(),
+            Side::Sell => self.bids.iter().collect(),
+            Side::Unknown => return None,
+        };
+        if side == Side::Buy {
+            levels.sort_by_key(|level| level.price);
+        } else {
+            levels.sort_by_key(|level| std::cmp::Reverse(level.price));
+        }
+
+        let mut filled = Decimal::ZERO;
+        levels.into_iter().find_map(|level| {
+            filled += level.size;
+            (filled >= size).then_some(level.price)
+        })
+    }
 }
 
 #[non_exhaustive]
@@ -188,6 +248,9 @@ pub struct MarketResponse {
     pub accepting_orders: bool,
     pub accepting_order_timestamp: Option<DateTime<Utc>>,
     pub minimum_order_size: Decimal,
+    /// Largest order size the market accepts, for markets that publish one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maximum_order_size: Option<Decimal>,
     pub minimum_tick_size: Decimal,
     /// The market condition ID (unique market identifier).
     #[serde_as(as = "NoneAsEmptyString")]
@@ -231,6 +294,21 @@ pub struct MarketResponse {
     pub tags: Vec<String>,
 }
 
+impl MarketResponse {
+    /// Returns whether the market's schedule has it accepting orders at `t`.
+    ///
+    /// A market opens at [`Self::accepting_order_timestamp`] (inclusive) and stops trading at
+    /// [`Self::end_date_iso`] (exclusive); a missing bound leaves that side open. Closed
+    /// markets are never open. The live [`Self::accepting_orders`] flag is ignored so that
+    /// quoting can be scheduled ahead of a market's opening.
+    #[must_use]
+    pub fn is_open_at(&self, t: DateTime<Utc>) -> bool {
+        !self.closed
+            && self.accepting_order_timestamp.is_none_or(|open| open <= t)
+            && self.end_date_iso.is_none_or(|end| t < end)
+    }
+}
+
 #[non_exhaustive]
 #[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq)]
 #[builder(on(String, into))]
@@ -303,6 +381,20 @@ pub struct PostOrderResponse {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub trade_ids: Vec<String>,
+    /// Executions from an order that crossed on submission; empty for resting orders.
+    #[builder(default)]
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    pub fills: Vec<Fill>,
+}
+
+/// A single execution reported in a [`PostOrderResponse`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
+pub struct Fill {
+    pub price: Decimal,
+    pub size: Decimal,
+    pub fee: Decimal,
 }
 
 pub fn empty_string_as_zero<'de, D>(deserializer: D) -> std::result::Result<Decimal, D::Error>
@@ -359,6 +451,9 @@ pub struct CancelOrdersResponse {
     #[serde_as(deserialize_as = "DefaultOnNull")]
     #[serde(alias = "not_canceled")]
     pub not_canceled: HashMap<String, String>,
+    /// USDC collateral released by the cancel, when the server reports it.
+    #[serde(default)]
+    pub freed_collateral: Option<Decimal>,
 }
 
 #[non_exhaustive]
@@ -808,3 +903,183 @@ pub struct RfqQuote {
     /// Quoted price.
     pub price: Decimal,
 }
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn book() -> OrderBookSummaryResponse {
+        let level = |price, size| OrderSummary::builder().price(price).size(size).build();
+
+        OrderBookSummaryResponse::builder()
+            .market(B256::ZERO)
+            .asset_id(U256::from(1))
+            .timestamp(DateTime::<Utc>::UNIX_EPOCH)
+            .bids(vec![
+                level(dec!(0.48), dec!(300)),
+                level(dec!(0.50), dec!(100)),
+                level(dec!(0.49), dec!(200)),
+            ])
+            .asks(vec![
+                level(dec!(0.53), dec!(70)),
+                level(dec!(0.51), dec!(50)),
+                level(dec!(0.52), dec!(60)),
+            ])
+            .min_order_size(dec!(5))
+            .neg_risk(false)
+            .tick_size(TickSize::Hundredth)
+            .build()
+    }
+
+    fn market(open: &str, end: Option<&str>, closed: bool) -> MarketResponse {
+        serde_json::from_value(serde_json::json!({
+            "enable_order_book": true,
+            "active": true,
+            "closed": closed,
+            "archived": false,
+            "accepting_orders": false,
+            "accepting_order_timestamp": open,
+            "minimum_order_size": "5",
+            "minimum_tick_size": "0.01",
+            "condition_id": "",
+            "question_id": "",
+            "question": "",
+            "description": "",
+            "market_slug": "",
+            "end_date_iso": end,
+            "game_start_time": null,
+            "seconds_delay": 0,
+            "fpmm": "",
+            "maker_base_fee": "0",
+            "taker_base_fee": "0",
+            "notifications_enabled": false,
+            "neg_risk": false,
+            "neg_risk_market_id": "",
+            "neg_risk_request_id": "",
+            "icon": "",
+            "image": "",
+            "rewards": { "rates": null, "min_size": "0", "max_spread": "0" },
+            "is_50_50_outcome": false,
+            "tokens": [],
+            "tags": []
+        }))
+        .expect("market should deserialize")
+    }
+
+    fn at(timestamp: &str) -> DateTime<Utc> {
+        timestamp.parse().expect("valid timestamp")
+    }
+
+    #[test]
+    fn is_open_at_should_respect_open_and_end_boundaries() {
+        let market = market("2024-01-15T12:00:00Z", Some("2024-02-01T00:00:00Z"), false);
+
+        assert!(
+            !market.is_open_at(at("2024-01-15T11:59:59Z")),
+            "before open"
+        );
+        assert!(market.is_open_at(at("2024-01-15T12:00:00Z")), "at open");
+        assert!(market.is_open_at(at("2024-01-31T23:59:59Z")), "before end");
+        assert!(!market.is_open_at(at("2024-02-01T00:00:00Z")), "at end");
+    }
+
+    #[test]
+    fn is_open_at_should_treat_missing_end_as_unbounded() {
+        let market = market("2024-01-15T12:00:00Z", None, false);
+
+        assert!(market.is_open_at(at("2030-01-01T00:00:00Z")), "no end date");
+    }
+
+    #[test]
+    fn is_open_at_should_reject_closed_markets() {
+        let market = market("2024-01-15T12:00:00Z", None, true);
+
+        assert!(
+            !market.is_open_at(at("2024-01-20T00:00:00Z")),
+            "closed market"
+        );
+    }
+
+    #[test]
+    fn queue_position_should_count_better_levels_and_same_level_size() {
+        let book = book();
+
+        assert_eq!(
+            book.queue_position(dec!(0.50), Side::Buy, dec!(10)),
+            dec!(90)
+        );
+        assert_eq!(
+            book.queue_position(dec!(0.49), Side::Buy, dec!(10)),
+            dec!(290)
+        );
+        assert_eq!(
+            book.queue_position(dec!(0.48), Side::Buy, dec!(300)),
+            dec!(300)
+        );
+        assert_eq!(
+            book.queue_position(dec!(0.52), Side::Sell, dec!(60)),
+            dec!(50)
+        );
+        assert_eq!(
+            book.queue_position(dec!(0.53), Side::Sell, dec!(20)),
+            dec!(160)
+        );
+    }
+
+    #[test]
+    fn marketable_price_should_walk_levels_best_first() {
+        let book = book();
+
+        assert_eq!(book.marketable_price(dec!(50), Side::Buy), Some(dec!(0.51)));
+        assert_eq!(book.marketable_price(dec!(51), Side::Buy), Some(dec!(0.52)));
+        assert_eq!(
+            book.marketable_price(dec!(180), Side::Buy),
+            Some(dec!(0.53))
+        );
+        assert_eq!(
+            book.marketable_price(dec!(100), Side::Sell),
+            Some(dec!(0.50))
+        );
+        assert_eq!(
+            book.marketable_price(dec!(250), Side::Sell),
+            Some(dec!(0.49))
+        );
+        assert_eq!(
+            book.marketable_price(dec!(600), Side::Sell),
+            Some(dec!(0.48))
+        );
+    }
+
+    #[test]
+    fn marketable_price_should_be_none_without_depth() {
+        let book = book();
+
+        assert_eq!(book.marketable_price(dec!(180.01), Side::Buy), None);
+        assert_eq!(book.marketable_price(dec!(601), Side::Sell), None);
+        assert_eq!(book.marketable_price(dec!(1), Side::Unknown), None);
+    }
+
+    #[test]
+    fn queue_position_should_handle_empty_levels_and_unknown_side() {
+        let book = book();
+
+        assert_eq!(
+            book.queue_position(dec!(0.47), Side::Buy, dec!(10)),
+            dec!(600)
+        );
+        assert_eq!(
+            book.queue_position(dec!(0.505), Side::Sell, dec!(10)),
+            Decimal::ZERO
+        );
+        assert_eq!(
+            book.queue_position(dec!(0.50), Side::Buy, dec!(500)),
+            Decimal::ZERO
+        );
+        assert_eq!(
+            book.queue_position(dec!(0.50), Side::Unknown, dec!(10)),
+            Decimal::ZERO
+        );
+    }
+}
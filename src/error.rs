@@ -1,6 +1,7 @@
 use std::backtrace::Backtrace;
 use std::error::Error as StdError;
 use std::fmt;
+use std::time::Duration;
 
 use alloy::primitives::ChainId;
 use alloy::primitives::ruint::ParseError;
@@ -26,6 +27,16 @@ pub enum Kind {
     WebSocket,
     /// Error related to geographic restrictions blocking access
     Geoblock,
+    /// No exchange contracts are configured for the chain id and neg-risk combination
+    MissingContractConfig,
+    /// A requested feature or policy mode is not implemented yet
+    Unsupported,
+    /// The server rejected a specific field of the request, e.g. an order's price or size
+    FieldValidation,
+    /// A caller-supplied deadline elapsed before the request completed
+    Timeout,
+    /// The exchange refused to cancel an order, e.g. because it was already matched
+    CancelRejected,
 }
 
 #[derive(Debug)]
@@ -83,10 +94,118 @@ impl Error {
         .into()
     }
 
+    /// Returns whether retrying the failed call unchanged may succeed.
+    ///
+    /// `true` for HTTP client timeouts, connection failures, `429 Too Many Requests` and
+    /// `5xx` responses, and for a [`Timeout`] that elapsed while queued for an order slot,
+    /// before anything was sent. `false` for a [`Timeout`] on the request itself, as the
+    /// deadline marks the order stale and it may have reached the server, and for validation
+    /// errors, other `4xx` responses and everything else.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        let is_retryable_status =
+            |code: StatusCode| code == StatusCode::TOO_MANY_REQUESTS || code.is_server_error();
+
+        if let Some(status) = self.downcast_ref::<Status>() {
+            return is_retryable_status(status.status_code);
+        }
+        if let Some(field_validation) = self.downcast_ref::<FieldValidation>() {
+            return field_validation
+                .status_code
+                .is_some_and(is_retryable_status);
+        }
+        if let Some(timeout) = self.downcast_ref::<Timeout>() {
+            return timeout.queued;
+        }
+        if let Some(err) = self.downcast_ref::<reqwest::Error>() {
+            return err.is_timeout()
+                || err.is_connect()
+                || err.status().is_some_and(is_retryable_status);
+        }
+
+        false
+    }
+
+    /// Returns the HTTP status code of a non-successful response, or `None` when the error
+    /// did not come from one.
+    #[must_use]
+    pub fn status_code(&self) -> Option<u16> {
+        if let Some(status) = self.downcast_ref::<Status>() {
+            return Some(status.status_code.as_u16());
+        }
+        if let Some(field_validation) = self.downcast_ref::<FieldValidation>() {
+            return field_validation.status_code.map(|code| code.as_u16());
+        }
+        self.downcast_ref::<reqwest::Error>()?
+            .status()
+            .map(|code| code.as_u16())
+    }
+
     #[must_use]
     pub fn missing_contract_config(chain_id: ChainId, neg_risk: bool) -> Self {
         MissingContractConfig { chain_id, neg_risk }.into()
     }
+
+    pub fn unsupported<S: Into<String>>(feature: S) -> Self {
+        Unsupported {
+            feature: feature.into(),
+        }
+        .into()
+    }
+
+    #[must_use]
+    pub fn timeout(deadline: Duration) -> Self {
+        Timeout {
+            deadline,
+            queued: false,
+        }
+        .into()
+    }
+
+    /// A [`Timeout`] that elapsed while waiting for an order slot, before the request was
+    /// sent.
+    #[must_use]
+    pub fn queue_timeout(deadline: Duration) -> Self {
+        Timeout {
+            deadline,
+            queued: true,
+        }
+        .into()
+    }
+
+    pub fn cancel_rejected<O: Into<String>, R: Into<String>>(order_id: O, reason: R) -> Self {
+        CancelRejected {
+            order_id: order_id.into(),
+            reason: reason.into(),
+        }
+        .into()
+    }
+
+    pub fn field_validation<F: Into<String>, M: Into<String>>(field: F, message: M) -> Self {
+        FieldValidation {
+            field: field.into(),
+            message: message.into(),
+            status_code: None,
+        }
+        .into()
+    }
+
+    /// Converts a `4xx` [`Status`] error whose body names the offending field into a
+    /// [`Kind::FieldValidation`] error, returning every other error unchanged.
+    ///
+    /// The response status is kept, so [`Self::status_code`] and [`Self::is_retryable`]
+    /// answer as they did for the original error.
+    #[cfg(feature = "clob")]
+    #[must_use]
+    pub(crate) fn into_field_validation(self) -> Self {
+        match self
+            .downcast_ref::<Status>()
+            .and_then(FieldValidation::from_status)
+        {
+            Some(field_validation) => field_validation.into(),
+            None => self,
+        }
+    }
 }
 
 impl fmt::Display for Error {
@@ -177,7 +296,170 @@ impl std::error::Error for MissingContractConfig {}
 
 impl From<MissingContractConfig> for Error {
     fn from(err: MissingContractConfig) -> Self {
-        Error::with_source(Kind::Internal, err)
+        Error::with_source(Kind::MissingContractConfig, err)
+    }
+}
+
+/// A feature or policy mode that is modeled but not implemented yet.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct Unsupported {
+    /// The unimplemented feature, e.g. `tick_size policy FetchAndCache`
+    pub feature: String,
+}
+
+impl fmt::Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is not implemented yet", self.feature)
+    }
+}
+
+impl std::error::Error for Unsupported {}
+
+impl From<Unsupported> for Error {
+    fn from(err: Unsupported) -> Self {
+        Error::with_source(Kind::Unsupported, err)
+    }
+}
+
+/// A request abandoned because its caller-supplied deadline elapsed first.
+///
+/// Unless it was still `queued`, the request may have reached the server before it was
+/// dropped.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy)]
+pub struct Timeout {
+    /// The deadline that elapsed
+    pub deadline: Duration,
+    /// Whether the deadline elapsed while waiting for an order slot, before anything was sent
+    pub queued: bool,
+}
+
+impl fmt::Display for Timeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.queued {
+            write!(f, "no order slot freed within {:?}", self.deadline)
+        } else {
+            write!(f, "request did not complete within {:?}", self.deadline)
+        }
+    }
+}
+
+impl StdError for Timeout {}
+
+impl From<Timeout> for Error {
+    fn from(err: Timeout) -> Self {
+        Error::with_source(Kind::Timeout, err)
+    }
+}
+
+/// An order the exchange reported as not canceled, with the reason it gave.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CancelRejected {
+    /// The order that is still live or already filled
+    pub order_id: String,
+    /// The exchange's reason, e.g. `order is matched`
+    pub reason: String,
+}
+
+impl fmt::Display for CancelRejected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "order {} was not canceled: {}", self.order_id, self.reason)
+    }
+}
+
+impl StdError for CancelRejected {}
+
+impl From<CancelRejected> for Error {
+    fn from(err: CancelRejected) -> Self {
+        Error::with_source(Kind::CancelRejected, err)
+    }
+}
+
+/// A rejection the server attributed to one field of the request, so a UI can highlight
+/// the offending input.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldValidation {
+    /// The rejected field, e.g. `price`, `size` or `expiration`
+    pub field: String,
+    /// The server's error message
+    pub message: String,
+    /// Status of the response that carried the rejection, or `None` when it was raised
+    /// without one
+    pub status_code: Option<StatusCode>,
+}
+
+impl FieldValidation {
+    /// Extracts the rejected field from a client error response.
+    ///
+    /// Uses the body's `field` when present, and otherwise recognizes the field from a fixed
+    /// list of known CLOB rejection messages. Returns `None` for server errors, rate limits
+    /// and any other message.
+    #[must_use]
+    pub fn from_status(status: &Status) -> Option<Self> {
+        if !status.status_code.is_client_error()
+            || status.status_code == StatusCode::TOO_MANY_REQUESTS
+        {
+            return None;
+        }
+
+        let body = serde_json::from_str::<serde_json::Value>(&status.message).ok();
+        let text = |key: &str| {
+            body.as_ref()
+                .and_then(|body| body.get(key))
+                .and_then(serde_json::Value::as_str)
+        };
+        let message = text("error")
+            .or_else(|| text("errorMsg"))
+            .or_else(|| text("message"))
+            .unwrap_or(&status.message)
+            .to_owned();
+        let field = match text("field") {
+            Some(field) => field.to_owned(),
+            None => field_from_message(&message)?.to_owned(),
+        };
+
+        Some(Self {
+            field,
+            message,
+            status_code: Some(status.status_code),
+        })
+    }
+}
+
+/// Known CLOB rejection messages that point at one field, as a fragment of the message and
+/// the field it names.
+const FIELD_MESSAGES: [(&str, &str); 3] = [
+    // "Price (0.001) breaks minimum tick size rule: 0.01"
+    ("breaks minimum tick size rule", "price"),
+    // "Size (1) lower than the minimum: 5"
+    ("lower than the minimum", "size"),
+    // "invalid expiration value, it must be at least 1 minute in the future"
+    ("invalid expiration value", "expiration"),
+];
+
+/// Returns the field a known CLOB rejection message refers to, or `None` for any other
+/// message.
+fn field_from_message(message: &str) -> Option<&'static str> {
+    FIELD_MESSAGES
+        .iter()
+        .find(|(fragment, _)| message.contains(fragment))
+        .map(|&(_, field)| field)
+}
+
+impl fmt::Display for FieldValidation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {}: {}", self.field, self.message)
+    }
+}
+
+impl StdError for FieldValidation {}
+
+impl From<FieldValidation> for Error {
+    fn from(err: FieldValidation) -> Self {
+        Error::with_source(Kind::FieldValidation, err)
     }
 }
 
@@ -311,4 +593,205 @@ mod tests {
         assert_eq!(error.kind(), Kind::Geoblock);
         assert!(error.to_string().contains("CU"));
     }
+
+    #[test]
+    fn missing_contract_config_into_error_should_succeed() {
+        let error = Error::missing_contract_config(1, true);
+
+        assert_eq!(error.kind(), Kind::MissingContractConfig);
+        let source = error
+            .downcast_ref::<MissingContractConfig>()
+            .expect("source should be MissingContractConfig");
+        assert_eq!(source.chain_id, 1);
+        assert!(source.neg_risk, "neg_risk should be preserved");
+    }
+
+    #[test]
+    fn is_retryable_should_classify_status_codes() {
+        let status = |code| Error::status(code, Method::POST, "/order".to_owned(), "");
+
+        assert!(
+            status(StatusCode::TOO_MANY_REQUESTS).is_retryable(),
+            "429 is retryable"
+        );
+        assert!(
+            status(StatusCode::BAD_GATEWAY).is_retryable(),
+            "5xx is retryable"
+        );
+        assert!(
+            !status(StatusCode::BAD_REQUEST).is_retryable(),
+            "400 is permanent"
+        );
+        assert!(
+            !status(StatusCode::UNAUTHORIZED).is_retryable(),
+            "401 is permanent"
+        );
+    }
+
+    #[test]
+    fn status_code_should_expose_response_status() {
+        for code in [
+            StatusCode::UNAUTHORIZED,
+            StatusCode::TOO_MANY_REQUESTS,
+            StatusCode::SERVICE_UNAVAILABLE,
+        ] {
+            let error = Error::status(code, Method::GET, "/data/orders".to_owned(), "");
+
+            assert_eq!(error.status_code(), Some(code.as_u16()));
+        }
+    }
+
+    #[test]
+    fn status_code_should_be_none_without_response() {
+        assert_eq!(Error::validation("bad price").status_code(), None);
+        assert_eq!(Error::missing_contract_config(1, false).status_code(), None);
+    }
+
+    #[test]
+    fn is_retryable_should_reject_validation_errors() {
+        assert!(
+            !Error::validation("bad price").is_retryable(),
+            "validation errors are permanent"
+        );
+        assert!(
+            !Error::missing_contract_config(1, false).is_retryable(),
+            "missing contract config is permanent"
+        );
+    }
+
+    #[test]
+    fn cancel_rejected_into_error_should_succeed() {
+        let error = Error::cancel_rejected("0x01", "order is matched");
+
+        assert_eq!(error.kind(), Kind::CancelRejected);
+        assert_eq!(
+            error.downcast_ref::<CancelRejected>().unwrap().reason,
+            "order is matched"
+        );
+        assert!(!error.is_retryable(), "the exchange refused the cancel");
+    }
+
+    #[test]
+    fn is_retryable_should_accept_queue_timeouts() {
+        let error = Error::queue_timeout(Duration::from_millis(50));
+
+        assert_eq!(error.kind(), Kind::Timeout);
+        assert!(error.is_retryable(), "nothing was sent, so retrying is safe");
+    }
+
+    #[test]
+    fn is_retryable_should_reject_request_deadlines() {
+        let error = Error::timeout(Duration::from_millis(50));
+
+        assert_eq!(error.kind(), Kind::Timeout);
+        assert!(
+            !error.is_retryable(),
+            "an order past its deadline is stale and may have been sent"
+        );
+    }
+
+    #[tokio::test]
+    async fn is_retryable_should_accept_connection_errors() {
+        // Nothing listens on port 1, so the connection is refused.
+        let err: Error = reqwest::get("http://127.0.0.1:1").await.unwrap_err().into();
+
+        assert!(err.is_retryable(), "connection errors are retryable");
+    }
+
+    #[cfg(feature = "clob")]
+    fn field_validation_of(code: StatusCode, body: &str) -> Option<FieldValidation> {
+        Error::status(code, Method::POST, "/order".to_owned(), body)
+            .into_field_validation()
+            .downcast_ref::<FieldValidation>()
+            .cloned()
+    }
+
+    #[cfg(feature = "clob")]
+    #[test]
+    fn into_field_validation_should_map_field_specific_rejections() {
+        let cases = [
+            (
+                r#"{"error":"order 0xabc is invalid. Price (0.001) breaks minimum tick size rule: 0.01"}"#,
+                "price",
+            ),
+            (
+                r#"{"error":"order 0xabc is invalid. Size (1) lower than the minimum: 5"}"#,
+                "size",
+            ),
+            (
+                r#"{"error":"invalid expiration value, it must be at least 1 minute in the future"}"#,
+                "expiration",
+            ),
+            (
+                r#"{"error":"must be positive","field":"feeRateBps"}"#,
+                "feeRateBps",
+            ),
+        ];
+
+        for (body, field) in cases {
+            let parsed = field_validation_of(StatusCode::BAD_REQUEST, body)
+                .unwrap_or_else(|| panic!("{body} should map to a field"));
+            assert_eq!(parsed.field, field, "field of {body}");
+        }
+    }
+
+    #[cfg(feature = "clob")]
+    #[test]
+    fn into_field_validation_should_keep_the_server_message() {
+        let error = Error::status(
+            StatusCode::BAD_REQUEST,
+            Method::POST,
+            "/order".to_owned(),
+            r#"{"error":"Size (1) lower than the minimum: 5"}"#,
+        )
+        .into_field_validation();
+
+        assert_eq!(error.kind(), Kind::FieldValidation);
+        assert_eq!(
+            error.downcast_ref::<FieldValidation>(),
+            Some(&FieldValidation {
+                field: "size".to_owned(),
+                message: "Size (1) lower than the minimum: 5".to_owned(),
+                status_code: Some(StatusCode::BAD_REQUEST),
+            })
+        );
+        assert_eq!(error.status_code(), Some(400));
+        assert!(!error.is_retryable(), "field rejections are permanent");
+    }
+
+    #[cfg(feature = "clob")]
+    #[test]
+    fn into_field_validation_should_leave_other_errors_unchanged() {
+        assert_eq!(
+            field_validation_of(
+                StatusCode::BAD_REQUEST,
+                r#"{"error":"not enough balance / allowance"}"#
+            ),
+            None
+        );
+        assert_eq!(
+            field_validation_of(
+                StatusCode::BAD_REQUEST,
+                r#"{"error":"the market for this size is closed"}"#
+            ),
+            None,
+            "messages outside the known list should not be guessed"
+        );
+        assert_eq!(
+            field_validation_of(
+                StatusCode::SERVICE_UNAVAILABLE,
+                r#"{"error":"invalid price"}"#
+            ),
+            None
+        );
+
+        let error = Error::status(
+            StatusCode::BAD_REQUEST,
+            Method::POST,
+            "/order".to_owned(),
+            "not enough balance",
+        )
+        .into_field_validation();
+        assert_eq!(error.kind(), Kind::Status);
+    }
 }
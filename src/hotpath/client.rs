@@ -1,45 +1,94 @@
-use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::iter;
 use std::str::FromStr as _;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicI64, AtomicU8, Ordering};
+use std::time::Duration;
 
 use alloy::dyn_abi::Eip712Domain;
 use alloy::primitives::U256;
-use alloy::signers::Signer as _;
 use alloy::signers::local::PrivateKeySigner;
+use alloy::signers::{Signer as _, SignerSync as _};
 use alloy::sol_types::SolStruct as _;
+use async_stream::try_stream;
 use chrono::{DateTime, Utc};
-use rand::Rng as _;
+use dashmap::DashMap;
+use futures::{Stream, StreamExt as _};
 use reqwest::Client as ReqwestClient;
-use reqwest::Method;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+use rust_decimal::RoundingStrategy;
 use rust_decimal::prelude::ToPrimitive as _;
 use secrecy::ExposeSecret as _;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use sha2::{Digest as _, Sha256};
+use tokio::sync::{Semaphore, SemaphorePermit};
 use url::Url;
 
 use crate::auth;
+#[cfg(feature = "ws")]
+use crate::auth::Kind as AuthKind;
 use crate::auth::state::Authenticated;
-use crate::auth::{Credentials, Normal};
-use crate::clob::types::response::PostOrderResponse;
-use crate::clob::types::{Order, OrderType, Side, SignatureType, SignedOrder};
-use crate::contract_config;
-use crate::error::{Error, Kind as ErrorKind};
+#[cfg(feature = "ws")]
+use crate::auth::state::State as WsState;
+use crate::auth::{ApiKey, Credentials, Normal};
+use crate::clob::types::request::{PriceRequest, TradesRequest};
+use crate::clob::types::response::{
+    BalanceAllowanceResponse, BanStatusResponse, CancelOrdersResponse, CurrentRewardResponse,
+    FeeRateResponse, MarketResponse, MarketRewardResponse, OpenOrderResponse,
+    OrderBookSummaryResponse, Page, PostOrderResponse, PricesResponse, TradeResponse,
+};
+use crate::clob::types::{
+    ORDER_NAME, ORDER_VERSION, Order, OrderStatusType, OrderType, Side, SignatureType, SignedOrder,
+    TradeStatusType, TraderSide, order_domain,
+};
+#[cfg(feature = "ws")]
+use crate::clob::ws::{Client as WsClient, WsMessage};
+use crate::error::{Error, Kind as ErrorKind, Status};
+#[cfg(feature = "tracing")]
+use crate::error::{FieldValidation, Validation};
+use crate::hotpath::policy::{
+    BUY_NOTIONAL_DECIMALS, GTD_EXPIRATION_BUFFER_SECS, GtdExpiration, NotionalRounding,
+    SELL_NOTIONAL_DECIMALS, USDC_DECIMALS,
+};
+use crate::hotpath::types::{
+    DerivedApiKey, MidpointQuote, NonceResponse, PriceQuote, TRADE_CSV_HEADER, trade_csv_row,
+    truncate_book,
+};
 use crate::hotpath::{
-    HotPathConfig, HotPathPolicies, LimitOrderOverrides, LimitOrderRequest, TimePolicy,
+    AuthLayer, Bbo, ClockTolerance, CredentialMode, CredentialsOrigin, ExportFormat, HotPathConfig,
+    HotPathPolicies, LimitOrderOverrides, LimitOrderRequest, MarketsPage, Notification, OrderDebug,
+    OrderSizeLimits, OrderStatus, Position, RetryPolicy, RewardInfo, SaltSource, TimePolicy,
+    TimeRandomSalt, TimestampSource, TradingReadiness,
 };
-use crate::types::{Address, ChainId, Decimal};
-use crate::{Result, Timestamp};
+use crate::types::{Address, B256, ChainId, Decimal, Signature};
+use crate::{Result, Timestamp, ToQueryParams as _};
+use crate::{contract_config, derive_safe_wallet};
 
-const ORDER_NAME: Option<Cow<'static, str>> = Some(Cow::Borrowed("Polymarket CTF Exchange"));
-const VERSION: Option<Cow<'static, str>> = Some(Cow::Borrowed("1"));
+/// Sentinel stored in the clock offset until [`HotPathClient::sync_clock`] has run.
+const CLOCK_NOT_SYNCED: i64 = i64::MIN;
+/// Sentinel stored as the last timestamp source until L2 headers have been built.
+const NO_TIMESTAMP_SOURCE: u8 = u8::MAX;
 
-const USDC_DECIMALS: u32 = 6;
-const LOT_SIZE_SCALE: u32 = 2;
+const TERMINAL_CURSOR: &str = "LTE="; // base64("-1")
+
+/// Upper bound on concurrent requests for multi-token market-data fetches.
+const MAX_CONCURRENT_FETCHES: usize = 8;
 
 /// High-throughput client optimized for limit `POST /order`.
+///
+/// Cloning is cheap: clones share the underlying `reqwest` connection pool and the cached
+/// server clock offset, while credentials and nonce are copied and can diverge per clone.
 #[derive(Clone, Debug)]
 pub struct HotPathClient {
     host: Url,
+    /// Host serving book, price and market reads, when split from `host`.
+    data_host: Option<Url>,
     chain_id: ChainId,
     nonce: Option<u32>,
+    credential_mode: CredentialMode,
     signer: PrivateKeySigner,
     signature_type: SignatureType,
     funder: Address,
@@ -47,12 +96,99 @@ pub struct HotPathClient {
     credentials: Credentials,
     state: Authenticated<Normal>,
     client: ReqwestClient,
+    /// EIP-712 domain of the standard exchange, resolved once at construction.
+    domain: Eip712Domain,
+    /// EIP-712 domain of the neg-risk exchange, resolved once at construction.
+    neg_risk_domain: Eip712Domain,
+    clock_tolerance: ClockTolerance,
+    /// Server minus local clock, in seconds, shared across clones.
+    clock_offset: Arc<AtomicI64>,
+    /// [`TimestampSource`] of the most recent L2 headers, shared across clones.
+    last_timestamp_source: Arc<AtomicU8>,
+    /// L2 headers signed during the current second, when enabled in the config.
+    l2_header_cache: Option<Arc<DashMap<L2HeaderKey, HeaderMap>>>,
+    salt_source: Arc<dyn SaltSource>,
+    credentials_origin: CredentialsOrigin,
+    /// Order nonce reported by `/auth/derive-api-key`; see [`Self::derived_nonce`].
+    derived_nonce: Option<u64>,
+    use_derived_nonce: bool,
+    /// Token ids orders may be signed for; empty means unrestricted.
+    allowed_token_ids: Arc<HashSet<U256>>,
+    max_order_size: Option<Decimal>,
+    max_order_notional: Option<Decimal>,
+    max_position: Option<Decimal>,
+    /// Net position per token for the `max_position` check, refreshed by [`Self::position`]
+    /// and raised by the size of every BUY signed since.
+    positions: Arc<DashMap<U256, Decimal>>,
+    market_order_size_limits: bool,
+    /// Published order size bounds per token, refreshed by [`Self::order_size_limits`].
+    order_size_limits: Arc<DashMap<U256, OrderSizeLimits>>,
+    /// Client-wide fee rate, preferred over the fee rate policy when set.
+    default_fee_rate_bps: Option<u32>,
+    /// Neg-risk flags pinned per token by [`Self::set_neg_risk`], preferred over the policy.
+    neg_risk_tokens: HashMap<U256, bool>,
+    /// Order ids per request in [`Self::cancel_all`].
+    cancel_chunk_size: usize,
+    /// Orders per request in [`Self::post_limit_orders`].
+    max_batch_size: usize,
+    /// Resting orders placed by this client, by order id; see [`Self::tracked_orders`].
+    tracked_orders: Option<Arc<DashMap<String, LimitOrderRequest>>>,
+    /// Best bid and offer per token from market WebSocket events; see [`Self::live_bbo`].
+    live_bbos: Arc<DashMap<U256, Bbo>>,
+    read_retry: RetryPolicy,
+    write_retry: RetryPolicy,
+    cancel_retry: RetryPolicy,
+    /// Slots for in-flight limit order submissions, shared across clones.
+    order_permits: Option<Arc<Semaphore>>,
+    order_permit_timeout: Option<Duration>,
+}
+
+/// Identifies an L2 signature: the same key, request and timestamp always sign identically.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct L2HeaderKey {
+    api_key: ApiKey,
+    request_hash: [u8; 32],
+    timestamp: Timestamp,
+}
+
+impl L2HeaderKey {
+    fn new(api_key: ApiKey, request: &reqwest::Request, timestamp: Timestamp) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(request.method().as_str());
+        hasher.update(request.url().path());
+        if let Some(body) = request.body().and_then(reqwest::Body::as_bytes) {
+            hasher.update(body);
+        }
+
+        Self {
+            api_key,
+            request_hash: hasher.finalize().into(),
+            timestamp,
+        }
+    }
+}
+
+/// An order that passed client-side validation, ready to be signed.
+struct PreparedOrder {
+    maker: Address,
+    signature_type: SignatureType,
+    neg_risk: bool,
+    fee_rate_bps: u32,
+    order_type: OrderType,
+    expiration: u64,
+    nonce: u64,
+    taker: Address,
+    post_only: bool,
+    maker_amount: U256,
+    taker_amount: U256,
 }
 
 impl HotPathClient {
-    /// Creates a new hot-path client and bootstraps credentials with L1 auth.
+    /// Creates a new hot-path client and bootstraps credentials with L1 auth, as selected by
+    /// [`HotPathConfig::credential_mode`].
     pub async fn bootstrap(config: HotPathConfig) -> Result<Self> {
-        Self::bootstrap_with_client(config, ReqwestClient::new()).await
+        let client = config.http_client()?;
+        Self::bootstrap_with_client(config, client).await
     }
 
     /// Creates a new hot-path client and bootstraps credentials with a custom HTTP client.
@@ -60,23 +196,33 @@ impl HotPathClient {
         config: HotPathConfig,
         client: ReqwestClient,
     ) -> Result<Self> {
+        config.validate_hosts()?;
+        config.validate_order_limits()?;
         let signer = Self::signer_from_config(&config)?;
-        let credentials = Self::create_or_derive_api_key(
+        if let Some(rpc_url) = &config.funder_rpc_url {
+            Self::validate_funder_code(&client, rpc_url, config.funder).await?;
+        }
+        let (credentials, origin, derived_nonce) = Self::create_or_derive_api_key(
             &client,
             &config.host,
             &signer,
             config.chain_id,
             config.nonce,
+            config.credential_mode,
             config.policies.time,
         )
         .await?;
 
-        Self::with_credentials_inner(config, signer, credentials, client)
+        let mut hotpath = Self::with_credentials_inner(config, signer, credentials, client)?;
+        hotpath.credentials_origin = origin;
+        hotpath.derived_nonce = derived_nonce;
+        Ok(hotpath)
     }
 
     /// Creates a hot-path client from already known credentials.
     pub fn with_credentials(config: HotPathConfig, credentials: Credentials) -> Result<Self> {
-        Self::with_credentials_and_client(config, credentials, ReqwestClient::new())
+        let client = config.http_client()?;
+        Self::with_credentials_and_client(config, credentials, client)
     }
 
     /// Creates a hot-path client from already known credentials and a custom HTTP client.
@@ -85,6 +231,8 @@ impl HotPathClient {
         credentials: Credentials,
         client: ReqwestClient,
     ) -> Result<Self> {
+        config.validate_hosts()?;
+        config.validate_order_limits()?;
         let signer = Self::signer_from_config(&config)?;
         Self::with_credentials_inner(config, signer, credentials, client)
     }
@@ -95,8 +243,15 @@ impl HotPathClient {
         credentials: Credentials,
         client: ReqwestClient,
     ) -> Result<Self> {
-        Self::validate_funder_signature(config.signature_type, config.funder)?;
+        Self::validate_funder_signature(
+            config.signature_type,
+            config.funder,
+            signer.address(),
+            config.chain_id,
+        )?;
 
+        let domain = order_domain(config.chain_id, false)?;
+        let neg_risk_domain = order_domain(config.chain_id, true)?;
         let state = Authenticated {
             address: signer.address(),
             credentials: credentials.clone(),
@@ -105,8 +260,10 @@ impl HotPathClient {
 
         Ok(Self {
             host: config.host,
+            data_host: config.data_host,
             chain_id: config.chain_id,
             nonce: config.nonce,
+            credential_mode: config.credential_mode,
             signer,
             signature_type: config.signature_type,
             funder: config.funder,
@@ -114,9 +271,46 @@ impl HotPathClient {
             credentials,
             state,
             client,
+            domain,
+            neg_risk_domain,
+            clock_tolerance: config.clock_tolerance,
+            clock_offset: Arc::new(AtomicI64::new(CLOCK_NOT_SYNCED)),
+            last_timestamp_source: Arc::new(AtomicU8::new(NO_TIMESTAMP_SOURCE)),
+            l2_header_cache: config.l2_header_cache.then(|| Arc::new(DashMap::new())),
+            salt_source: Arc::new(TimeRandomSalt),
+            credentials_origin: CredentialsOrigin::Supplied,
+            derived_nonce: None,
+            use_derived_nonce: config.use_derived_nonce,
+            allowed_token_ids: Arc::new(config.allowed_token_ids),
+            max_order_size: config.max_order_size,
+            max_order_notional: config.max_order_notional,
+            max_position: config.max_position,
+            positions: Arc::new(DashMap::new()),
+            market_order_size_limits: config.market_order_size_limits,
+            order_size_limits: Arc::new(DashMap::new()),
+            default_fee_rate_bps: None,
+            neg_risk_tokens: HashMap::new(),
+            cancel_chunk_size: config.cancel_chunk_size,
+            max_batch_size: config.max_batch_size,
+            tracked_orders: config.track_orders.then(|| Arc::new(DashMap::new())),
+            live_bbos: Arc::new(DashMap::new()),
+            read_retry: config.read_retry,
+            write_retry: config.write_retry,
+            cancel_retry: config.cancel_retry,
+            order_permits: config
+                .max_in_flight_orders
+                .map(|permits| Arc::new(Semaphore::new(permits.get()))),
+            order_permit_timeout: config.order_permit_timeout,
         })
     }
 
+    /// Replaces the source of order salts, [`TimeRandomSalt`] by default.
+    #[must_use]
+    pub fn with_salt_source<S: SaltSource>(mut self, salt_source: S) -> Self {
+        self.salt_source = Arc::new(salt_source);
+        self
+    }
+
     fn signer_from_config(config: &HotPathConfig) -> Result<PrivateKeySigner> {
         PrivateKeySigner::from_str(config.private_key.expose_secret())
             .map_err(|e| Error::validation(format!("invalid private key: {e}")))
@@ -128,325 +322,3684 @@ impl HotPathClient {
         self.signer.address()
     }
 
+    /// Returns the address of the key that signs orders and auth headers.
+    ///
+    /// Same as [`Self::address`].
+    #[must_use]
+    pub fn signer_address(&self) -> Address {
+        self.signer.address()
+    }
+
+    /// Returns the funder, the proxy or Safe wallet that holds the funds and makes orders.
+    #[must_use]
+    pub fn funder(&self) -> Address {
+        self.funder
+    }
+
     #[must_use]
     pub fn credentials(&self) -> &Credentials {
         &self.credentials
     }
 
-    /// Recreates or derives API credentials and updates internal L2 auth state.
+    /// Returns how the current credentials were obtained.
+    ///
+    /// [`CredentialsOrigin::Derived`] means creation was rejected and the key already existed.
+    #[must_use]
+    pub fn credentials_origin(&self) -> CredentialsOrigin {
+        self.credentials_origin
+    }
+
+    /// Returns the order nonce `/auth/derive-api-key` reported for the current credentials,
+    /// or `None` when they were created, supplied, or derived without one.
+    ///
+    /// With [`HotPathConfig::use_derived_nonce`](crate::hotpath::HotPathConfig::use_derived_nonce)
+    /// set, orders whose [`LimitOrderRequest::nonce`] is unset are signed with this nonce, so
+    /// they match the derived key's nonce instead of defaulting to `0`.
+    #[must_use]
+    pub fn derived_nonce(&self) -> Option<u64> {
+        self.derived_nonce
+    }
+
+    /// Returns the policies the client was built with, e.g. to log them at startup.
+    #[must_use]
+    pub fn policies(&self) -> &HotPathPolicies {
+        &self.policies
+    }
+
+    /// Returns the resting orders this client placed and hasn't canceled, by order id, or an
+    /// empty map unless [`HotPathConfig::track_orders`] is set.
+    ///
+    /// Orders placed with a [`LimitOrderRequest`] enter the map when the exchange accepts
+    /// them as `Live`. A cancel removes its orders optimistically, before the request is
+    /// sent, and puts them back if the request fails or the exchange reports them in
+    /// `not_canceled`. While a cancel is in flight the map may therefore miss orders that
+    /// are still resting, but it never shows an order a reprice loop has already pulled, and
+    /// it settles on the exchange's answer once the cancel returns. Fills and expirations
+    /// aren't observed: a filled order stays until it is canceled.
+    #[must_use]
+    pub fn tracked_orders(&self) -> HashMap<String, LimitOrderRequest> {
+        self.tracked_orders
+            .as_ref()
+            .map(|orders| {
+                orders
+                    .iter()
+                    .map(|entry| (entry.key().clone(), entry.value().clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Fetches `/time` and caches the offset between server and local clocks.
+    ///
+    /// Returns the offset in seconds (server minus local).
+    pub async fn sync_clock(&self) -> Result<i64> {
+        let server_time = self
+            .get_public::<Timestamp>(self.endpoint("time")?, &[])
+            .await?;
+        let offset = server_time - Utc::now().timestamp();
+        self.clock_offset.store(offset, Ordering::Relaxed);
+        Ok(offset)
+    }
+
+    /// Returns the cached server clock offset in seconds, if [`Self::sync_clock`] has run.
+    #[must_use]
+    pub fn clock_offset(&self) -> Option<i64> {
+        let offset = self.clock_offset.load(Ordering::Relaxed);
+        (offset != CLOCK_NOT_SYNCED).then_some(offset)
+    }
+
+    /// Whether the timestamp this client would put on `layer` headers right now is within the
+    /// configured [`ClockTolerance`] of server time.
+    ///
+    /// Each layer is checked against the timestamp its own headers use: L2 headers follow
+    /// the [policy clock](TimePolicy), while L1 headers use local time under
+    /// [`TimePolicy::Fixed`] and the server time read from `/time` under
+    /// [`TimePolicy::FetchAndCache`].
+    ///
+    /// Returns `None` until [`Self::sync_clock`] has cached a server clock offset.
+    #[must_use]
+    pub fn timestamp_within_tolerance(&self, layer: AuthLayer) -> Option<bool> {
+        let offset = self.clock_offset()?;
+        let server_now = Utc::now().timestamp() + offset;
+
+        let timestamp = match (layer, self.policies.time) {
+            (AuthLayer::L1, TimePolicy::FetchAndCache) => server_now,
+            (AuthLayer::L1, _) => Utc::now().timestamp(),
+            (AuthLayer::L2, _) => self.l2_timestamp(None).0,
+        };
+        let skew = (timestamp - server_now).abs();
+
+        Some(skew <= self.clock_tolerance.for_layer(layer))
+    }
+
+    /// Returns where the timestamp on the most recent L2 headers came from, or `None` before
+    /// any were built.
+    ///
+    /// [`TimestampSource::ServerOffset`] confirms [`TimePolicy::FetchAndCache`] engaged.
+    #[must_use]
+    pub fn last_timestamp_source(&self) -> Option<TimestampSource> {
+        match self.last_timestamp_source.load(Ordering::Relaxed) {
+            0 => Some(TimestampSource::Override),
+            1 => Some(TimestampSource::Local),
+            2 => Some(TimestampSource::ServerOffset),
+            _ => None,
+        }
+    }
+
+    /// Recreates or derives API credentials, as selected by
+    /// [`HotPathConfig::credential_mode`], and updates internal L2 auth state.
     ///
     /// Intended for recovery flow after `401/403` responses.
     pub async fn refresh_credentials(&mut self) -> Result<&Credentials> {
-        let credentials = Self::create_or_derive_api_key(
+        self.refresh_credentials_inner(self.nonce).await
+    }
+
+    /// Like [`Self::refresh_credentials`], but signs the L1 auth message with `nonce` instead
+    /// of the configured one.
+    ///
+    /// The L1 nonce selects which API key is created or derived, so rotating it per attempt
+    /// keeps keys from separate runs apart.
+    pub async fn refresh_credentials_with_nonce(&mut self, nonce: u32) -> Result<&Credentials> {
+        self.refresh_credentials_inner(Some(nonce)).await
+    }
+
+    async fn refresh_credentials_inner(&mut self, nonce: Option<u32>) -> Result<&Credentials> {
+        let (credentials, origin, derived_nonce) = Self::create_or_derive_api_key(
             &self.client,
             &self.host,
             &self.signer,
             self.chain_id,
-            self.nonce,
+            nonce,
+            self.credential_mode,
             self.policies.time,
         )
         .await?;
 
+        self.set_credentials(credentials);
+        self.credentials_origin = origin;
+        self.derived_nonce = derived_nonce;
+        Ok(&self.credentials)
+    }
+
+    /// Replaces the API credentials used for L2 auth on this client only.
+    ///
+    /// Clones keep their own credentials, so one process can trade several API keys over a
+    /// single connection pool.
+    pub fn set_credentials(&mut self, credentials: Credentials) {
+        if let Some(cache) = &self.l2_header_cache {
+            cache.clear();
+        }
+        self.credentials_origin = CredentialsOrigin::Supplied;
+        self.derived_nonce = None;
         self.state.credentials = credentials.clone();
         self.credentials = credentials;
-        Ok(&self.credentials)
     }
 
-    /// Signs and submits a limit order with default fixed policies.
-    pub async fn post_limit_order(&self, request: &LimitOrderRequest) -> Result<PostOrderResponse> {
-        self.post_limit_order_with_overrides(request, LimitOrderOverrides::default())
-            .await
+    /// Sets the fee rate applied to every order from this client that has no per-order
+    /// [`LimitOrderOverrides::fee_rate_bps`], taking precedence over the fee rate policy.
+    pub fn set_default_fee_rate_bps(&mut self, bps: u32) {
+        self.default_fee_rate_bps = Some(bps);
     }
 
-    /// Signs and submits a limit order with per-order overrides.
+    /// Pins `token_id`'s neg-risk flag for every later order from this client that has no
+    /// per-order [`LimitOrderOverrides::neg_risk`], taking precedence over the neg-risk policy.
     ///
-    /// If an override is not provided, default fixed policy values are used.
-    pub async fn post_limit_order_with_overrides(
-        &self,
-        request: &LimitOrderRequest,
-        overrides: LimitOrderOverrides,
-    ) -> Result<PostOrderResponse> {
-        let signed = self.sign_limit_order(request, overrides).await?;
-        self.post_signed_order(signed, overrides.timestamp).await
+    /// Use it once a token's market type is known, e.g. from
+    /// [`MarketResponse::neg_risk`](crate::clob::types::response::MarketResponse::neg_risk),
+    /// so orders are signed against the right exchange without asking again.
+    pub fn set_neg_risk(&mut self, token_id: U256, neg_risk: bool) {
+        self.neg_risk_tokens.insert(token_id, neg_risk);
     }
 
-    /// Builds and signs a limit order.
-    pub async fn sign_limit_order(
-        &self,
-        request: &LimitOrderRequest,
-        overrides: LimitOrderOverrides,
-    ) -> Result<SignedOrder> {
-        let tick_size = overrides
-            .tick_size
-            .map_or_else(|| self.policies.default_tick_size(), Ok)?;
-        let neg_risk = overrides
-            .neg_risk
-            .map_or_else(|| self.policies.default_neg_risk(), Ok)?;
-        let fee_rate_bps = overrides
-            .fee_rate_bps
-            .map_or_else(|| self.policies.default_fee_rate_bps(), Ok)?;
+    /// Returns the midpoint for `token_id`, or `None` when the book has no liquidity.
+    #[expect(
+        clippy::same_name_method,
+        reason = "`ClobMarketData` delegates to this method under the same name"
+    )]
+    pub async fn get_midpoint(&self, token_id: U256) -> Result<Option<Decimal>> {
+        let quote = self
+            .get_market_data::<MidpointQuote>("midpoint", &[("token_id", token_id.to_string())])
+            .await?;
+        Ok(quote.and_then(|quote| quote.mid))
+    }
 
-        let order_type = request.order_type.clone().unwrap_or(OrderType::GTC);
-        let expiration = request.expiration.unwrap_or(DateTime::<Utc>::UNIX_EPOCH);
-        let nonce = request.nonce.unwrap_or(0);
-        let taker = request.taker.unwrap_or(Address::ZERO);
-        let post_only = request.post_only.unwrap_or(false);
+    /// Returns the price of the most recent trade in `token_id`, or `None` when the token has
+    /// not traded.
+    #[expect(
+        clippy::same_name_method,
+        reason = "`ClobMarketData` delegates to this method under the same name"
+    )]
+    pub async fn get_last_trade_price(&self, token_id: U256) -> Result<Option<Decimal>> {
+        let quote = self
+            .get_market_data::<PriceQuote>(
+                "last-trade-price",
+                &[("token_id", token_id.to_string())],
+            )
+            .await?;
+        Ok(quote.and_then(|quote| quote.price))
+    }
 
-        if !matches!(order_type, OrderType::GTD) && expiration > DateTime::<Utc>::UNIX_EPOCH {
-            return Err(Error::validation(
-                "Only GTD orders may have a non-zero expiration",
-            ));
-        }
-        if post_only && !matches!(order_type, OrderType::GTC | OrderType::GTD) {
-            return Err(Error::validation(
-                "postOnly is only supported for GTC and GTD orders",
-            ));
-        }
+    /// Returns the best price for `token_id` on `side`, or `None` when that side is empty.
+    pub async fn get_price(&self, token_id: U256, side: Side) -> Result<Option<Decimal>> {
+        let quote = self
+            .get_market_data::<PriceQuote>(
+                "price",
+                &[
+                    ("token_id", token_id.to_string()),
+                    ("side", side.to_string()),
+                ],
+            )
+            .await?;
+        Ok(quote.and_then(|quote| quote.price))
+    }
 
-        let price = request.price;
-        let size = request.size;
-        let side = request.side;
+    /// Returns the order book for `token_id`, or `None` when the market has no order book.
+    ///
+    /// With `depth`, each side is sorted best price first and truncated to the top `depth`
+    /// levels client-side, since the CLOB always returns the full book. The server's `hash`
+    /// then no longer describes the returned levels.
+    #[expect(
+        clippy::same_name_method,
+        reason = "`ClobMarketData` delegates to this method under the same name"
+    )]
+    pub async fn get_order_book(
+        &self,
+        token_id: U256,
+        depth: Option<usize>,
+    ) -> Result<Option<OrderBookSummaryResponse>> {
+        let mut book = self
+            .get_market_data::<OrderBookSummaryResponse>(
+                "book",
+                &[("token_id", token_id.to_string())],
+            )
+            .await?;
 
-        if price.is_sign_negative() {
-            return Err(Error::validation(format!(
-                "Unable to build Order due to negative price {price}"
-            )));
-        }
-        if size.is_zero() || size.is_sign_negative() {
-            return Err(Error::validation(format!(
-                "Unable to build Order due to negative size {size}"
-            )));
+        if let (Some(book), Some(depth)) = (book.as_mut(), depth) {
+            truncate_book(book, depth);
         }
-        if size.scale() > LOT_SIZE_SCALE {
-            return Err(Error::validation(format!(
-                "Unable to build Order: Size {size} has {} decimal places. Maximum lot size is {LOT_SIZE_SCALE}",
-                size.scale()
-            )));
+
+        Ok(book)
+    }
+
+    /// Returns the best bid and offer for `token_id`.
+    ///
+    /// An empty or missing book yields a [`Bbo`] with both sides set to `None`.
+    #[expect(
+        clippy::same_name_method,
+        reason = "`ClobMarketData` delegates to this method under the same name"
+    )]
+    pub async fn get_bbo(&self, token_id: U256) -> Result<Bbo> {
+        let book = self.get_order_book(token_id, None).await?;
+        Ok(book.as_ref().map_or_else(Bbo::default, Bbo::from_book))
+    }
+
+    /// Returns the best bid and offer for each of `token_ids`, fetched concurrently.
+    ///
+    /// At most eight books are requested at once. Each token maps to its own result, so one
+    /// failed fetch leaves the others intact. Tokens without an order book map to an empty
+    /// [`Bbo`], as in [`Self::get_bbo`].
+    pub async fn get_bbos(&self, token_ids: &[U256]) -> HashMap<U256, Result<Bbo>> {
+        futures::stream::iter(token_ids.iter().copied())
+            .map(|token_id| async move { (token_id, self.get_bbo(token_id).await) })
+            .buffer_unordered(MAX_CONCURRENT_FETCHES)
+            .collect()
+            .await
+    }
+
+    /// Returns the latest best bid and offer for `token_id` seen on the market WebSocket,
+    /// without a network call, or `None` before the first event for that token.
+    ///
+    /// The cache is shared across clones and fed by [`Self::run_live_bbo`] or
+    /// [`Self::record_market_event`].
+    #[must_use]
+    pub fn live_bbo(&self, token_id: U256) -> Option<Bbo> {
+        self.live_bbos.get(&token_id).map(|bbo| *bbo)
+    }
+
+    /// Updates the [`Self::live_bbo`] cache from a market WebSocket event.
+    ///
+    /// Book snapshots replace the token's BBO. Price changes and best bid/ask events
+    /// overwrite the sides they carry. Other events are ignored.
+    #[cfg(feature = "ws")]
+    pub fn record_market_event(&self, message: &WsMessage) {
+        match message {
+            WsMessage::Book(book) => {
+                let bbo = Bbo {
+                    best_bid: book.bids.iter().map(|level| level.price).max(),
+                    best_ask: book.asks.iter().map(|level| level.price).min(),
+                };
+                self.live_bbos.insert(book.asset_id, bbo);
+            }
+            WsMessage::PriceChange(change) => {
+                for entry in &change.price_changes {
+                    self.update_live_bbo(entry.asset_id, entry.best_bid, entry.best_ask);
+                }
+            }
+            WsMessage::BestBidAsk(best) => {
+                self.update_live_bbo(best.asset_id, Some(best.best_bid), Some(best.best_ask));
+            }
+            _ => {}
         }
+    }
 
-        let minimum_tick_size = tick_size.as_decimal();
-        let decimals = minimum_tick_size.scale();
+    /// Subscribes to book and price change events for `token_ids` on `ws` and feeds them
+    /// into the [`Self::live_bbo`] cache until the subscription ends.
+    ///
+    /// Spawn it next to the quoting loop, e.g. on a clone of this client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription cannot be created or the stream yields one.
+    #[cfg(feature = "ws")]
+    pub async fn run_live_bbo<S: WsState>(
+        &self,
+        ws: &WsClient<S>,
+        token_ids: Vec<U256>,
+    ) -> Result<()> {
+        let books = ws
+            .subscribe_orderbook(token_ids.clone())?
+            .map_ok(WsMessage::Book);
+        let prices = ws
+            .subscribe_prices(token_ids)?
+            .map_ok(WsMessage::PriceChange);
+        let mut events = std::pin::pin!(futures::stream::select(books, prices));
 
-        if price.scale() > minimum_tick_size.scale() {
-            return Err(Error::validation(format!(
-                "Unable to build Order: Price {price} has {} decimal places. Minimum tick size \
-                {minimum_tick_size} has {} decimal places. Price decimal places <= minimum tick size decimal places",
-                price.scale(),
-                minimum_tick_size.scale()
-            )));
+        while let Some(message) = events.next().await {
+            self.record_market_event(&message?);
         }
-        if price < minimum_tick_size || price > Decimal::ONE - minimum_tick_size {
-            return Err(Error::validation(format!(
-                "Price {price} is too small or too large for the minimum tick size {minimum_tick_size}"
-            )));
+
+        Ok(())
+    }
+
+    /// Posts `request` like [`Self::post_limit_order`] and returns the response together
+    /// with the order's updates from the user channel of `ws`.
+    ///
+    /// The user channel for `market`, the order's condition id, is subscribed before the
+    /// order is sent, so no update can slip through between submission and monitoring. The
+    /// stream yields the [`WsMessage::Order`] events for the order and the
+    /// [`WsMessage::Trade`] events that fill it, as taker or maker. A rejected order never
+    /// produces events, so check the response first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription cannot be created or the order fails to post.
+    #[cfg(feature = "ws")]
+    pub async fn post_and_watch<K: AuthKind>(
+        &self,
+        ws: &WsClient<Authenticated<K>>,
+        market: B256,
+        request: &LimitOrderRequest,
+    ) -> Result<(PostOrderResponse, impl Stream<Item = Result<WsMessage>>)> {
+        let events = ws.subscribe_user_events(vec![market])?;
+        let response = self.post_limit_order(request).await?;
+
+        let order_id = response.order_id.clone();
+        let updates = events.filter(move |message| {
+            let is_match = match message {
+                Ok(WsMessage::Order(order)) => order.id == order_id,
+                Ok(WsMessage::Trade(trade)) => {
+                    trade.taker_order_id.as_deref() == Some(order_id.as_str())
+                        || trade
+                            .maker_orders
+                            .iter()
+                            .any(|maker| maker.order_id == order_id)
+                }
+                Ok(_) => false,
+                Err(_) => true,
+            };
+            futures::future::ready(is_match)
+        });
+
+        Ok((response, updates))
+    }
+
+    /// Returns the price for every `(token_id, side)` query in a single `POST /prices`
+    /// request.
+    ///
+    /// Queries the CLOB has no price for, such as tokens without an order book, are absent
+    /// from the returned map.
+    pub async fn get_prices(
+        &self,
+        queries: &[(U256, Side)],
+    ) -> Result<HashMap<(U256, Side), Decimal>> {
+        if queries.is_empty() {
+            return Ok(HashMap::new());
         }
 
-        let (taker_amount, maker_amount) = match side {
-            Side::Buy => (
-                size,
-                (size * price).trunc_with_scale(decimals + LOT_SIZE_SCALE),
-            ),
-            Side::Sell => (
-                (size * price).trunc_with_scale(decimals + LOT_SIZE_SCALE),
-                size,
-            ),
-            other => return Err(Error::validation(format!("Invalid side: {other}"))),
-        };
+        let body: Vec<PriceRequest> = queries
+            .iter()
+            .map(|&(token_id, side)| {
+                PriceRequest::builder()
+                    .token_id(token_id)
+                    .side(side)
+                    .build()
+            })
+            .collect();
+        let url = self.data_endpoint("prices")?;
+        let prices = with_retries(self.read_retry, || async {
+            let request = self
+                .client
+                .request(Method::POST, url.clone())
+                .json(&body)
+                .build()?;
+            crate::request::<PricesResponse>(&self.client, request, None).await
+        })
+        .await?
+        .prices
+        .unwrap_or_default();
 
-        let expiration_u64 = expiration
-            .timestamp()
-            .to_u64()
-            .ok_or(Error::validation(format!(
-                "Unable to represent expiration {expiration} as a u64"
-            )))?;
+        Ok(queries
+            .iter()
+            .filter_map(|&(token_id, side)| {
+                let price = *prices.get(&token_id)?.get(&side)?;
+                Some(((token_id, side), price))
+            })
+            .collect())
+    }
 
-        let order = Order {
-            salt: U256::from(to_ieee_754_int(generate_seed())),
-            maker: self.funder,
-            signer: self.address(),
-            taker,
-            tokenId: request.token_id,
-            makerAmount: U256::from(to_fixed_u128(maker_amount)?),
-            takerAmount: U256::from(to_fixed_u128(taker_amount)?),
-            expiration: U256::from(expiration_u64),
-            nonce: U256::from(nonce),
-            feeRateBps: U256::from(fee_rate_bps),
-            side: side as u8,
-            signatureType: self.signature_type as u8,
-        };
+    /// Returns the CLOB market for `condition_id`.
+    pub async fn get_market(&self, condition_id: &str) -> Result<MarketResponse> {
+        self.get_public(self.data_endpoint(&format!("markets/{condition_id}"))?, &[])
+            .await
+    }
+
+    /// Returns a page of sampling markets, the currently liquid markets in the rewards program.
+    ///
+    /// Pass the previous page's `next_cursor` to continue; a `next_cursor` of `LTE=` marks the
+    /// last page.
+    pub async fn get_sampling_markets(&self, cursor: Option<String>) -> Result<MarketsPage> {
+        let query: Vec<(&str, String)> = cursor
+            .into_iter()
+            .map(|cursor| ("next_cursor", cursor))
+            .collect();
+        self.get_public(self.data_endpoint("sampling-markets")?, &query)
+            .await
+    }
 
-        let exchange_contract = contract_config(self.chain_id, neg_risk)
-            .ok_or(Error::missing_contract_config(self.chain_id, neg_risk))?
-            .exchange;
-        let domain = Eip712Domain {
-            name: ORDER_NAME,
-            version: VERSION,
-            chain_id: Some(U256::from(self.chain_id)),
-            verifying_contract: Some(exchange_contract),
-            ..Eip712Domain::default()
+    /// Returns whether the market trading `token_id` is open and accepting orders.
+    ///
+    /// The market is resolved through the token's order book, so a token without a book,
+    /// as is the case once a market closes, reports `false`.
+    pub async fn is_market_active(&self, token_id: U256) -> Result<bool> {
+        let Some(book) = self
+            .get_market_data::<OrderBookSummaryResponse>(
+                "book",
+                &[("token_id", token_id.to_string())],
+            )
+            .await?
+        else {
+            return Ok(false);
         };
-        let signature = self
-            .signer
-            .sign_hash(&order.eip712_signing_hash(&domain))
-            .await?;
 
-        Ok(SignedOrder {
-            order,
-            signature,
-            order_type,
-            owner: self.credentials.key(),
-            post_only: Some(post_only),
-        })
+        let market = self.get_market(&book.market.to_string()).await?;
+        Ok(market.active && market.accepting_orders && !market.closed)
     }
 
-    /// Posts an already-signed order to `/order`.
-    pub async fn post_signed_order(
-        &self,
-        signed_order: SignedOrder,
-        timestamp_override: Option<Timestamp>,
-    ) -> Result<PostOrderResponse> {
-        let request = self
-            .client
-            .request(Method::POST, self.endpoint("order")?)
-            .json(&signed_order)
-            .build()?;
-        let headers = self.create_l2_headers(&request, timestamp_override).await?;
+    /// Returns the order size bounds the market trading `token_id` publishes.
+    ///
+    /// The market is resolved through the token's order book. The bounds are cached for
+    /// every token of the market and used by [`Self::sign_limit_order`] when
+    /// [`HotPathConfig::market_order_size_limits`](crate::hotpath::HotPathConfig::market_order_size_limits)
+    /// is set; call this again to refresh them.
+    pub async fn order_size_limits(&self, token_id: U256) -> Result<OrderSizeLimits> {
+        let book = self
+            .get_market_data::<OrderBookSummaryResponse>(
+                "book",
+                &[("token_id", token_id.to_string())],
+            )
+            .await?
+            .ok_or_else(|| Error::validation(format!("No order book for token {token_id}")))?;
+        let market = self.get_market(&book.market.to_string()).await?;
 
-        crate::request::<PostOrderResponse>(&self.client, request, Some(headers)).await
+        let limits = OrderSizeLimits {
+            min: market.minimum_order_size,
+            max: market.maximum_order_size,
+        };
+        for token in iter::once(token_id).chain(market.tokens.iter().map(|token| token.token_id)) {
+            self.order_size_limits.insert(token, limits);
+        }
+        Ok(limits)
     }
 
-    async fn create_or_derive_api_key(
-        client: &ReqwestClient,
-        host: &Url,
-        signer: &PrivateKeySigner,
-        chain_id: ChainId,
-        nonce: Option<u32>,
-        time_policy: TimePolicy,
-    ) -> Result<Credentials> {
-        match Self::create_api_key(client, host, signer, chain_id, nonce, time_policy).await {
-            Ok(creds) => Ok(creds),
-            Err(err) if err.kind() == ErrorKind::Status => {
-                Self::derive_api_key(client, host, signer, chain_id, nonce, time_policy).await
+    /// Returns the `[YES, NO]` outcome token ids for `condition_id`.
+    ///
+    /// Markets whose outcomes are not labelled `Yes`/`No` are returned in the order listed by
+    /// the CLOB.
+    pub async fn token_ids(&self, condition_id: &str) -> Result<[U256; 2]> {
+        let market = self.get_market(condition_id).await?;
+        binary_token_ids(&market)
+    }
+
+    /// Aggregates this account's fills in `token_id` into a net [`Position`].
+    ///
+    /// Walks every page of `/data/trades`, counting taker fills and this account's maker
+    /// fills in chronological order. Failed trades are ignored.
+    ///
+    /// Also refreshes the position checked against
+    /// [`HotPathConfig::max_position`](crate::hotpath::HotPathConfig::max_position).
+    pub async fn position(&self, token_id: U256) -> Result<Position> {
+        let mut trades = self
+            .get_all_pages::<TradeResponse>("data/trades", &[("asset_id", token_id.to_string())])
+            .await?;
+        trades.sort_by_key(|trade| trade.match_time);
+
+        let owner = self.credentials.key();
+        let mut position = Position::new(token_id);
+        for trade in trades
+            .iter()
+            .filter(|trade| trade.status != TradeStatusType::Failed)
+        {
+            if trade.trader_side == TraderSide::Taker {
+                if trade.owner == owner && trade.asset_id == token_id {
+                    position.apply_fill(trade.side, trade.size, trade.price);
+                }
+            } else {
+                for maker_order in trade
+                    .maker_orders
+                    .iter()
+                    .filter(|order| order.owner == owner && order.asset_id == token_id)
+                {
+                    position.apply_fill(
+                        maker_order.side,
+                        maker_order.matched_amount,
+                        maker_order.price,
+                    );
+                }
             }
-            Err(err) => Err(err),
         }
+
+        self.positions.insert(token_id, position.size);
+        Ok(position)
     }
 
-    async fn create_api_key(
-        client: &ReqwestClient,
-        host: &Url,
-        signer: &PrivateKeySigner,
-        chain_id: ChainId,
-        nonce: Option<u32>,
-        time_policy: TimePolicy,
-    ) -> Result<Credentials> {
-        let request = client
-            .request(Method::POST, host.join("auth/api-key")?)
-            .build()?;
-        let headers =
-            Self::create_l1_headers(signer, chain_id, nonce, time_policy, host, client).await?;
+    /// Streams every trade matching `filter` from `/data/trades` into `writer`, one page at
+    /// a time, so the full history is never held in memory.
+    ///
+    /// [`ExportFormat::Csv`] writes a header row, then one row per trade;
+    /// [`ExportFormat::JsonLines`] writes each trade object as returned by the API. The
+    /// writer is flushed after the last page. On error, rows from earlier pages have already
+    /// been written.
+    pub async fn export_trades<W: Write>(
+        &self,
+        filter: &TradesRequest,
+        mut writer: W,
+        format: ExportFormat,
+    ) -> Result<()> {
+        let io_error = |e| Error::with_source(ErrorKind::Internal, e);
 
-        crate::request::<Credentials>(client, request, Some(headers)).await
+        if format == ExportFormat::Csv {
+            writeln!(writer, "{TRADE_CSV_HEADER}").map_err(io_error)?;
+        }
+
+        let mut cursor: Option<String> = None;
+        loop {
+            let path = format!("data/trades{}", filter.query_params(cursor.as_deref()));
+            let page = self
+                .get_authenticated::<Page<serde_json::Value>>(&path, &[])
+                .await?;
+
+            for trade in page.data {
+                match format {
+                    ExportFormat::Csv => {
+                        let trade = serde_json::from_value::<TradeResponse>(trade)?;
+                        writeln!(writer, "{}", trade_csv_row(&trade))
+                    }
+                    ExportFormat::JsonLines => writeln!(writer, "{trade}"),
+                }
+                .map_err(io_error)?;
+            }
+
+            if page.next_cursor == TERMINAL_CURSOR || page.next_cursor.is_empty() {
+                break;
+            }
+            cursor = Some(page.next_cursor);
+        }
+
+        writer.flush().map_err(io_error)
     }
 
-    async fn derive_api_key(
-        client: &ReqwestClient,
-        host: &Url,
-        signer: &PrivateKeySigner,
-        chain_id: ChainId,
-        nonce: Option<u32>,
-        time_policy: TimePolicy,
-    ) -> Result<Credentials> {
-        let request = client
-            .request(Method::GET, host.join("auth/derive-api-key")?)
+    /// Returns the active liquidity reward programs, or only those for `market` when given a
+    /// condition id.
+    ///
+    /// Walks every page of the rewards endpoint; markets without a reward program are absent.
+    pub async fn get_rewards(&self, market: Option<&str>) -> Result<Vec<RewardInfo>> {
+        let rewards = match market {
+            Some(condition_id) => self
+                .get_all_pages::<MarketRewardResponse>(
+                    &format!("rewards/markets/{condition_id}"),
+                    &[],
+                )
+                .await?
+                .into_iter()
+                .map(RewardInfo::from)
+                .collect(),
+            None => self
+                .get_all_pages::<CurrentRewardResponse>("rewards/markets/current", &[])
+                .await?
+                .into_iter()
+                .map(RewardInfo::from)
+                .collect(),
+        };
+
+        Ok(rewards)
+    }
+
+    /// Returns whether this account may still open new positions.
+    ///
+    /// Restricted accounts are put in closed-only mode: they can cancel orders and close
+    /// positions, but new orders are rejected. The CLOB only reports this for the
+    /// authenticated account, so arbitrary addresses cannot be checked.
+    pub async fn is_account_allowed(&self) -> Result<bool> {
+        let status = self
+            .get_authenticated::<BanStatusResponse>("auth/ban-status/closed-only", &[])
+            .await?;
+        Ok(!status.closed_only)
+    }
+
+    /// Checks that this account can trade before the first order is sent.
+    ///
+    /// Verifies the API credentials with a cheap authenticated call, then reads the funder's
+    /// collateral balance and the allowance granted to each exchange contract (standard and
+    /// neg-risk). An allowance is sufficient when it covers the whole balance. Rejected
+    /// credentials are reported in the result rather than as an error; other failures are
+    /// returned as errors.
+    pub async fn check_trading_ready(&self) -> Result<TradingReadiness> {
+        match self
+            .get_authenticated::<BanStatusResponse>("auth/ban-status/closed-only", &[])
+            .await
+        {
+            Ok(_) => {}
+            Err(err) if is_auth_rejection(&err) => {
+                return Ok(TradingReadiness {
+                    credentials_valid: false,
+                    balance: None,
+                    insufficient_allowances: Vec::new(),
+                });
+            }
+            Err(err) => return Err(err),
+        }
+
+        let query = [
+            ("asset_type", "COLLATERAL".to_owned()),
+            ("signature_type", (self.signature_type as u8).to_string()),
+        ];
+        let response = self
+            .get_authenticated::<BalanceAllowanceResponse>("balance-allowance", &query)
+            .await?;
+
+        let required = U256::from(response.balance.trunc().to_u128().unwrap_or(u128::MAX));
+        let mut insufficient_allowances = Vec::new();
+        for exchange in [self.exchange_for(false)?, self.exchange_for(true)?] {
+            let allowance = response
+                .allowances
+                .get(&exchange)
+                .and_then(|allowance| U256::from_str_radix(allowance, 10).ok())
+                .unwrap_or(U256::ZERO);
+            if allowance.is_zero() || allowance < required {
+                insufficient_allowances.push(exchange);
+            }
+        }
+
+        Ok(TradingReadiness {
+            credentials_valid: true,
+            balance: Some(response.balance),
+            insufficient_allowances,
+        })
+    }
+
+    /// Returns this account's pending notifications, such as fills and market resolutions.
+    pub async fn get_notifications(&self) -> Result<Vec<Notification>> {
+        let query = [("signature_type", (self.signature_type as u8).to_string())];
+        self.get_authenticated("notifications", &query).await
+    }
+
+    /// Marks the notifications with `ids` as read so they are no longer returned.
+    pub async fn mark_notifications_read(&self, ids: &[String]) -> Result<()> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut request = self
+            .client
+            .request(Method::DELETE, self.endpoint("notifications")?)
+            .query(&[("ids", ids.join(","))])
             .build()?;
-        let headers =
-            Self::create_l1_headers(signer, chain_id, nonce, time_policy, host, client).await?;
+        *request.headers_mut() = self.create_l2_headers(&request, None).await?;
 
-        crate::request::<Credentials>(client, request, Some(headers)).await
+        // The endpoint replies without a body, so `crate::request` would fail to decode it.
+        let response = self.client.execute(request).await?;
+        let status_code = response.status();
+        if !status_code.is_success() {
+            let path = response.url().path().to_owned();
+            let message = response.text().await.unwrap_or_default();
+            return Err(Error::status(status_code, Method::DELETE, path, message));
+        }
+
+        Ok(())
     }
 
-    async fn create_l1_headers(
-        signer: &PrivateKeySigner,
-        chain_id: ChainId,
-        nonce: Option<u32>,
-        time_policy: TimePolicy,
-        _host: &Url,
-        _client: &ReqwestClient,
-    ) -> Result<reqwest::header::HeaderMap> {
-        let timestamp = resolve_timestamp(time_policy, None)?;
-        auth::l1::create_headers(signer, chain_id, timestamp, nonce).await
+    /// Returns the effective fee rate in basis points for this account.
+    ///
+    /// Useful for accounts with a negotiated rate; feed the result into
+    /// [`LimitOrderOverrides::with_fee_rate_bps`] or a fixed fee policy.
+    pub async fn get_fee_rate_bps(&self) -> Result<u32> {
+        let response = self
+            .get_authenticated::<FeeRateResponse>("fee-rate", &[])
+            .await?;
+        Ok(response.base_fee)
     }
 
-    async fn create_l2_headers(
+    /// Returns the account's current order nonce as tracked by the server.
+    ///
+    /// Orders signed with a lower nonce than this value are no longer valid on the exchange.
+    pub async fn get_nonce(&self) -> Result<u32> {
+        let response = self
+            .get_authenticated::<NonceResponse>("nonce", &[])
+            .await?;
+        Ok(response.nonce)
+    }
+
+    /// Polls `order_id` once, then again on every item of `ticks`, and yields its
+    /// [`OrderStatus`] whenever it changes.
+    ///
+    /// `ticks` sets the polling cadence, e.g. a `tokio::time::interval` wrapped as a stream,
+    /// so the caller picks the timer. The stream ends after yielding a terminal status (fully
+    /// filled, canceled or unmatched), when `ticks` ends, or after the first error.
+    pub fn watch_order<'client, T: Stream + 'client>(
+        &'client self,
+        order_id: &'client str,
+        ticks: T,
+    ) -> impl Stream<Item = Result<OrderStatus>> + 'client {
+        try_stream! {
+            let path = format!("data/order/{order_id}");
+            let mut last: Option<OrderStatus> = None;
+            let mut ticks = std::pin::pin!(ticks);
+
+            loop {
+                let order = self.get_authenticated::<OpenOrderResponse>(&path, &[]).await?;
+                let status = OrderStatus::from(order);
+                let terminal = status.is_terminal();
+
+                if last.as_ref() != Some(&status) {
+                    last = Some(status.clone());
+                    yield status;
+                }
+
+                if terminal || ticks.next().await.is_none() {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Signs and submits a limit order with default fixed policies.
+    ///
+    /// An accepted order can come back as [`OrderStatusType::Delayed`](crate::clob::types::OrderStatusType::Delayed): it is neither
+    /// rejected nor filled yet, and later resolves to `Matched` or `Unmatched`, which
+    /// [`Self::watch_order`] can follow.
+    #[expect(
+        clippy::same_name_method,
+        reason = "`ClobOrders` delegates to this method under the same name"
+    )]
+    pub async fn post_limit_order(&self, request: &LimitOrderRequest) -> Result<PostOrderResponse> {
+        self.post_limit_order_with_overrides(request, LimitOrderOverrides::default())
+            .await
+    }
+
+    /// Signs and submits a limit order with per-order overrides.
+    ///
+    /// If an override is not provided, default fixed policy values are used. The order is
+    /// posted once; use [`Self::post_limit_order_reliable`] to retry.
+    ///
+    /// With [`HotPathConfig::max_in_flight_orders`](crate::hotpath::HotPathConfig::max_in_flight_orders)
+    /// set, waits for a free slot before signing, failing with a retryable
+    /// [timeout](crate::error::Kind::Timeout) error if
+    /// [`HotPathConfig::order_permit_timeout`](crate::hotpath::HotPathConfig::order_permit_timeout)
+    /// elapses first.
+    #[expect(
+        clippy::same_name_method,
+        reason = "`ClobOrders` delegates to this method under the same name"
+    )]
+    pub async fn post_limit_order_with_overrides(
         &self,
-        request: &reqwest::Request,
-        timestamp_override: Option<Timestamp>,
-    ) -> Result<reqwest::header::HeaderMap> {
-        let timestamp = resolve_timestamp(self.policies.time, timestamp_override)?;
-        auth::l2::create_headers(&self.state, request, timestamp).await
+        request: &LimitOrderRequest,
+        overrides: LimitOrderOverrides,
+    ) -> Result<PostOrderResponse> {
+        let _permit = self.acquire_order_permit().await?;
+        let result = match self.sign_limit_order(request, overrides).await {
+            Ok(signed) => self.post_order_body(&signed, overrides.timestamp).await,
+            Err(err) => Err(err),
+        };
+
+        #[cfg(feature = "tracing")]
+        log_rejection(request, &result);
+        self.track_order(request, &result);
+
+        result
     }
 
-    fn endpoint(&self, path: &str) -> Result<Url> {
-        Ok(self.host.join(path)?)
+    /// Signs a limit order once and submits it, retrying with backoff per
+    /// [`HotPathConfig::write_retry`](crate::hotpath::HotPathConfig::write_retry) when the
+    /// failure is [retryable](Error::is_retryable).
+    ///
+    /// Every attempt resends the identical signed order, salt included, so its order hash
+    /// never changes: if an attempt reached the exchange before failing, the retry is
+    /// recognised as the same order instead of placing a second one.
+    pub async fn post_limit_order_reliable(
+        &self,
+        request: &LimitOrderRequest,
+    ) -> Result<PostOrderResponse> {
+        let _permit = self.acquire_order_permit().await?;
+        let result = match self
+            .sign_limit_order(request, LimitOrderOverrides::default())
+            .await
+        {
+            Ok(signed) => {
+                with_retries(self.write_retry, || self.post_order_body(&signed, None)).await
+            }
+            Err(err) => Err(err),
+        };
+
+        #[cfg(feature = "tracing")]
+        log_rejection(request, &result);
+        self.track_order(request, &result);
+
+        result
     }
 
-    fn validate_funder_signature(signature_type: SignatureType, funder: Address) -> Result<()> {
-        if matches!(signature_type, SignatureType::Eoa) {
-            return Err(Error::validation(
-                "Cannot have a funder address with an Eoa signature type",
-            ));
+    /// Cancels the order with the server-assigned `order_id`.
+    ///
+    /// With order tracking enabled, the order leaves [`Self::tracked_orders`] before the
+    /// request is sent and returns if the cancel fails.
+    #[expect(
+        clippy::same_name_method,
+        reason = "`ClobOrders` delegates to this method under the same name"
+    )]
+    pub async fn cancel_order(&self, order_id: &str) -> Result<CancelOrdersResponse> {
+        let body = serde_json::json!({ "orderId": order_id });
+        self.cancel_tracked("order", &body, &[order_id]).await
+    }
+
+    /// Replaces the resting order `order_id` with `request`: cancels it, then posts
+    /// `request` once the exchange confirms the cancel.
+    ///
+    /// The CLOB has no amend endpoint, so every replacement, including a size-only
+    /// reduction at the same price, cancels and reposts, and the new order joins the back
+    /// of the queue at its price level.
+    ///
+    /// # Errors
+    ///
+    /// Returns the cancel's error, or a [`Kind::CancelRejected`](ErrorKind::CancelRejected)
+    /// error carrying the exchange's reason, without posting, when the exchange doesn't
+    /// report `order_id` as canceled, so the old and new orders never rest together.
+    pub async fn replace_order(
+        &self,
+        order_id: &str,
+        request: &LimitOrderRequest,
+    ) -> Result<PostOrderResponse> {
+        let canceled = self.cancel_order(order_id).await?;
+        if !canceled.canceled.iter().any(|id| id == order_id) {
+            let reason = canceled
+                .not_canceled
+                .get(order_id)
+                .map_or("not canceled", String::as_str);
+            return Err(Error::cancel_rejected(order_id, reason));
         }
-        if funder == Address::ZERO {
-            return Err(Error::validation(
-                "Cannot have a zero funder address with a proxy signature type",
-            ));
+
+        self.post_limit_order(request).await
+    }
+
+    /// Cancels the orders with the server-assigned `order_ids` in one `DELETE /orders` request.
+    ///
+    /// Updates [`Self::tracked_orders`] like [`Self::cancel_order`].
+    pub async fn cancel_orders(&self, order_ids: &[String]) -> Result<CancelOrdersResponse> {
+        self.cancel_tracked("orders", order_ids, order_ids).await
+    }
+
+    /// Cancels `orders` in one `DELETE /orders` request and reports the collateral freed
+    /// in [`CancelOrdersResponse::freed_collateral`], so it can be reused without
+    /// re-querying balances.
+    ///
+    /// `orders` are the caller's view of the resting orders, e.g. from [`Self::watch_order`].
+    /// With `None`, every order in [`Self::tracked_orders`] is canceled instead, which
+    /// requires order tracking. A freed amount reported by the server is returned as is.
+    /// Otherwise it's estimated as the unfilled notional of every canceled BUY order:
+    /// `(original_size - size_matched) * price` for the given orders, and `size * price` for
+    /// tracked ones, whose fills aren't observed. SELL orders lock outcome tokens rather than
+    /// collateral and free none. Fills not reflected in the orders make the estimate an
+    /// upper bound.
+    pub async fn cancel_orders_with_freed_collateral(
+        &self,
+        orders: Option<&[OpenOrderResponse]>,
+    ) -> Result<CancelOrdersResponse> {
+        let Some(orders) = orders else {
+            return self.cancel_tracked_with_freed_collateral().await;
+        };
+
+        let order_ids: Vec<String> = orders.iter().map(|order| order.id.clone()).collect();
+        let mut response = self.cancel_orders(&order_ids).await?;
+        if response.freed_collateral.is_none() {
+            response.freed_collateral = Some(freed_collateral(orders, &response.canceled));
         }
-        Ok(())
+
+        Ok(response)
     }
-}
 
-fn resolve_timestamp(
-    policy: TimePolicy,
-    override_timestamp: Option<Timestamp>,
-) -> Result<Timestamp> {
-    if let Some(ts) = override_timestamp {
-        return Ok(ts);
+    /// Cancels every open order, in chunks of
+    /// [`HotPathConfig::cancel_chunk_size`](crate::hotpath::HotPathConfig::cancel_chunk_size)
+    /// order ids per request.
+    ///
+    /// A chunk failing with a [retryable](Error::is_retryable) error is retried per
+    /// [`HotPathConfig::cancel_retry`](crate::hotpath::HotPathConfig::cancel_retry).
+    /// A chunk that still fails doesn't stop the rest: its order ids are reported in
+    /// `not_canceled` with the error, so the result accounts for every open order.
+    pub async fn cancel_all(&self) -> Result<CancelOrdersResponse> {
+        let order_ids: Vec<String> = self
+            .get_all_pages::<OpenOrderResponse>("data/orders", &[])
+            .await?
+            .into_iter()
+            .map(|order| order.id)
+            .collect();
+
+        let mut result = CancelOrdersResponse::default();
+        for chunk in order_ids.chunks(self.cancel_chunk_size.max(1)) {
+            match with_retries(self.cancel_retry, || self.cancel_orders(chunk)).await {
+                Ok(response) => {
+                    result.canceled.extend(response.canceled);
+                    result.not_canceled.extend(response.not_canceled);
+                }
+                Err(err) => {
+                    let reason = err.to_string();
+                    result
+                        .not_canceled
+                        .extend(chunk.iter().map(|id| (id.clone(), reason.clone())));
+                }
+            }
+        }
+
+        Ok(result)
     }
 
-    match policy {
-        TimePolicy::Fixed => Ok(Utc::now().timestamp()),
-        TimePolicy::FetchAndCache => Err(Error::validation(
-            "time policy FetchAndCache is not implemented in hotpath yet",
-        )),
+    /// Cancels an order by its EIP-712 hash, as returned by [`Self::order_signing_hash`].
+    ///
+    /// The CLOB uses the order's EIP-712 hash as its order id, so no lookup is needed and
+    /// this works for orders signed by any client.
+    pub async fn cancel_order_by_hash(&self, hash: B256) -> Result<CancelOrdersResponse> {
+        self.cancel_order(&hash.to_string()).await
     }
-}
 
-/// Removes trailing zeros, truncates to 6 decimals, and quantizes as integer.
-fn to_fixed_u128(d: Decimal) -> Result<u128> {
-    if d.is_sign_negative() {
-        return Err(Error::validation(format!("amount cannot be negative: {d}")));
+    /// Signs and submits several limit orders with `POST /orders`, splitting batches larger
+    /// than [`HotPathConfig::max_batch_size`](crate::hotpath::HotPathConfig::max_batch_size)
+    /// across sequential requests.
+    ///
+    /// Results are returned per order, in input order. An order that fails validation, or
+    /// whose entry in the response is malformed, yields its own `Err` without affecting the
+    /// rest. The outer `Err` is reserved for the first request failing as a whole, such as
+    /// on transport or auth errors, when nothing has been submitted; once a request has gone
+    /// through, a later failed request instead fails each of its own orders.
+    ///
+    /// With [`HotPathConfig::max_in_flight_orders`](crate::hotpath::HotPathConfig::max_in_flight_orders)
+    /// set, each request waits for a free slot before it is sent.
+    #[expect(
+        clippy::same_name_method,
+        reason = "`ClobOrders` delegates to this method under the same name"
+    )]
+    pub async fn post_limit_orders(
+        &self,
+        requests: &[LimitOrderRequest],
+    ) -> Result<Vec<Result<PostOrderResponse>>> {
+        self.post_limit_orders_with_timestamp(requests, None).await
     }
 
-    d.normalize()
-        .trunc_with_scale(USDC_DECIMALS)
-        .mantissa()
-        .to_u128()
-        .ok_or(Error::validation(format!(
-            "unable to represent amount as u128: {d}"
-        )))
-}
+    /// Like [`Self::post_limit_orders`], but signs the batch's L2 headers with
+    /// `timestamp_override` when set, aligning a burst of orders to one timestamp.
+    pub async fn post_limit_orders_with_timestamp(
+        &self,
+        requests: &[LimitOrderRequest],
+        timestamp_override: Option<Timestamp>,
+    ) -> Result<Vec<Result<PostOrderResponse>>> {
+        let mut results = Vec::with_capacity(requests.len());
+        let mut signed_orders = Vec::with_capacity(requests.len());
+        for request in requests {
+            match self
+                .sign_limit_order(request, LimitOrderOverrides::default())
+                .await
+            {
+                Ok(signed) => {
+                    signed_orders.push(signed);
+                    results.push(None);
+                }
+                Err(e) => results.push(Some(Err(e))),
+            }
+        }
 
-/// Mask salt to <= 2^53 - 1 because backend parses as IEEE 754.
-fn to_ieee_754_int(salt: u64) -> u64 {
-    salt & ((1 << 53) - 1)
-}
+        if signed_orders.is_empty() {
+            return Ok(results.into_iter().flatten().collect());
+        }
+
+        let missing_entry =
+            || Error::validation("batch response is missing an entry for this order");
+        let mut responses = Vec::with_capacity(signed_orders.len());
+        for (index, chunk) in signed_orders.chunks(self.max_batch_size.max(1)).enumerate() {
+            let posted = async {
+                let _permit = self.acquire_order_permit().await?;
+                self.post_order_batch(chunk, timestamp_override).await
+            };
+            match posted.await {
+                // Pad short responses so later chunks stay aligned with their orders.
+                Ok(entries) => responses.extend(
+                    entries
+                        .into_iter()
+                        .map(Ok)
+                        .chain(iter::repeat_with(|| Err(missing_entry())))
+                        .take(chunk.len()),
+                ),
+                Err(err) if index == 0 => return Err(err),
+                Err(err) => responses.extend(chunk.iter().map(|_| Err(batch_error(&err)))),
+            }
+        }
+        let mut responses = responses.into_iter();
+
+        let results: Vec<_> = results
+            .into_iter()
+            .map(|result| {
+                result.unwrap_or_else(|| {
+                    let entry = responses.next().unwrap_or_else(|| Err(missing_entry()))?;
+                    serde_json::from_value(entry).map_err(Error::from)
+                })
+            })
+            .collect();
+
+        for (request, result) in requests.iter().zip(&results) {
+            #[cfg(feature = "tracing")]
+            log_rejection(request, result);
+            self.track_order(request, result);
+        }
+
+        Ok(results)
+    }
+
+    /// Runs every client-side check [`Self::sign_limit_order`] applies, without signing
+    /// or any network I/O.
+    ///
+    /// Returns the same validation error `sign_limit_order` would for invalid input, which
+    /// makes it suitable for validating user input as it is entered. With
+    /// [`HotPathConfig::max_position`](crate::hotpath::HotPathConfig::max_position) or
+    /// [`HotPathConfig::market_order_size_limits`](crate::hotpath::HotPathConfig::market_order_size_limits)
+    /// set, the order is checked against the cached position or bounds, and rejected while
+    /// none are cached for its token; call [`Self::position`] or
+    /// [`Self::order_size_limits`] first.
+    pub fn validate_order(
+        &self,
+        request: &LimitOrderRequest,
+        overrides: LimitOrderOverrides,
+    ) -> Result<()> {
+        self.prepare_order(request, overrides).map(|_| ())
+    }
+
+    /// Builds and signs a limit order.
+    ///
+    /// With [`HotPathConfig::max_position`](crate::hotpath::HotPathConfig::max_position) set,
+    /// a BUY that would take the net position in its token above the cap is rejected. The
+    /// position is fetched with [`Self::position`] on the first order for a token and cached,
+    /// and each signed BUY adds its size to it, so orders still resting or in flight count
+    /// against the cap. Call `position` again to resync it after fills or cancels. SELLs
+    /// only reduce exposure, so they always pass and leave the cached position unchanged.
+    ///
+    /// With [`HotPathConfig::market_order_size_limits`](crate::hotpath::HotPathConfig::market_order_size_limits)
+    /// set, the size must also fall within the bounds the market publishes, fetched with
+    /// [`Self::order_size_limits`] on the first order for a token and cached. These bounds
+    /// are checked separately from the client's own caps.
+    pub async fn sign_limit_order(
+        &self,
+        request: &LimitOrderRequest,
+        overrides: LimitOrderOverrides,
+    ) -> Result<SignedOrder> {
+        self.fetch_position_on_miss(request).await?;
+        self.fetch_order_size_limits_on_miss(request).await?;
+        let prepared = self.prepare_order(request, overrides)?;
+        let order = self.unsigned_order(request, &prepared, self.salt_source.next_salt());
+
+        let signature = self
+            .signer
+            .sign_hash(&self.order_signing_hash(&order, prepared.neg_risk))
+            .await?;
+        self.reserve_position(request)?;
+
+        Ok(self.signed_order(order, signature, prepared))
+    }
+
+    /// Signs `request` with `salt`, like [`Self::sign_limit_order`], and returns every
+    /// intermediate value alongside the body that would be posted, without posting it.
+    ///
+    /// Meant for parity tests against `py-clob-client`: pass both clients the same salt.
+    /// Purely local: the client's [`SaltSource`] is left untouched, and the position and
+    /// market size caps, which may need network I/O, are not checked.
+    pub fn debug_order_payload(
+        &self,
+        request: &LimitOrderRequest,
+        overrides: LimitOrderOverrides,
+        salt: u64,
+    ) -> Result<OrderDebug> {
+        let prepared = self.build_order(request, overrides)?;
+        let neg_risk = prepared.neg_risk;
+        let order = self.unsigned_order(request, &prepared, salt);
+
+        let signing_hash = self.order_signing_hash(&order, neg_risk);
+        let signature = self.signer.sign_hash_sync(&signing_hash)?;
+        let signed = self.signed_order(order, signature, prepared);
+
+        Ok(OrderDebug {
+            salt: signed.order.salt,
+            maker_amount: signed.order.makerAmount,
+            taker_amount: signed.order.takerAmount,
+            domain_name: ORDER_NAME.unwrap_or_default().into_owned(),
+            domain_version: ORDER_VERSION.unwrap_or_default().into_owned(),
+            chain_id: self.chain_id,
+            verifying_contract: self.exchange_for(neg_risk)?,
+            signing_hash,
+            body: serde_json::to_string(&signed)?,
+        })
+    }
+
+    /// Blocking variant of [`Self::sign_limit_order`] for callers outside an async runtime.
+    ///
+    /// Signing is purely local, so this produces exactly what `sign_limit_order` would for
+    /// the same salt. It can't fetch the position for
+    /// [`HotPathConfig::max_position`](crate::hotpath::HotPathConfig::max_position) or the
+    /// bounds for
+    /// [`HotPathConfig::market_order_size_limits`](crate::hotpath::HotPathConfig::market_order_size_limits),
+    /// so an order is rejected while they aren't cached for its token; call
+    /// [`Self::position`] or [`Self::order_size_limits`] first.
+    pub fn sign_limit_order_blocking(
+        &self,
+        request: &LimitOrderRequest,
+        overrides: LimitOrderOverrides,
+    ) -> Result<SignedOrder> {
+        let prepared = self.prepare_order(request, overrides)?;
+        let order = self.unsigned_order(request, &prepared, self.salt_source.next_salt());
+
+        let signature = self
+            .signer
+            .sign_hash_sync(&self.order_signing_hash(&order, prepared.neg_risk))?;
+        self.reserve_position(request)?;
 
-fn generate_seed() -> u64 {
-    let now = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .expect("time went backwards");
-    let seconds = now.as_secs_f64();
-    let random = rand::rng().random::<f64>();
-    (seconds * random).round() as u64
+        Ok(self.signed_order(order, signature, prepared))
+    }
+
+    /// Assembles the order to sign from validated parameters.
+    fn unsigned_order(
+        &self,
+        request: &LimitOrderRequest,
+        prepared: &PreparedOrder,
+        salt: u64,
+    ) -> Order {
+        Order {
+            salt: U256::from(to_ieee_754_int(salt)),
+            maker: prepared.maker,
+            signer: self.address(),
+            taker: prepared.taker,
+            tokenId: request.token_id,
+            makerAmount: prepared.maker_amount,
+            takerAmount: prepared.taker_amount,
+            expiration: U256::from(prepared.expiration),
+            nonce: U256::from(prepared.nonce),
+            feeRateBps: U256::from(prepared.fee_rate_bps),
+            side: request.side as u8,
+            signatureType: prepared.signature_type as u8,
+        }
+    }
+
+    fn signed_order(
+        &self,
+        order: Order,
+        signature: Signature,
+        prepared: PreparedOrder,
+    ) -> SignedOrder {
+        SignedOrder {
+            order,
+            signature,
+            order_type: prepared.order_type,
+            owner: self.credentials.key(),
+            post_only: Some(prepared.post_only),
+        }
+    }
+
+    /// Resolves policy defaults and applies all client-side order checks.
+    fn prepare_order(
+        &self,
+        request: &LimitOrderRequest,
+        overrides: LimitOrderOverrides,
+    ) -> Result<PreparedOrder> {
+        let prepared = self.build_order(request, overrides)?;
+        if let Some(current) = self.cached_position(request)? {
+            self.check_max_position(request, current)?;
+        }
+        self.check_market_order_size(request)?;
+
+        Ok(prepared)
+    }
+
+    /// Like [`Self::prepare_order`], without the position and market size caps, whose
+    /// values come from the network.
+    fn build_order(
+        &self,
+        request: &LimitOrderRequest,
+        overrides: LimitOrderOverrides,
+    ) -> Result<PreparedOrder> {
+        if !self.allowed_token_ids.is_empty() && !self.allowed_token_ids.contains(&request.token_id)
+        {
+            return Err(Error::validation(format!(
+                "Token id {} is not in the allowed token ids",
+                request.token_id
+            )));
+        }
+
+        let tick_size = overrides
+            .tick_size
+            .map_or_else(|| self.policies.default_tick_size(), Ok)?;
+        let neg_risk = overrides
+            .neg_risk
+            .or_else(|| self.neg_risk_tokens.get(&request.token_id).copied())
+            .map_or_else(|| self.policies.default_neg_risk(), Ok)?;
+        let fee_rate_bps = overrides
+            .fee_rate_bps
+            .or(self.default_fee_rate_bps)
+            .map_or_else(|| self.policies.default_fee_rate_bps(), Ok)?;
+        let signature_type = overrides.signature_type.unwrap_or(self.signature_type);
+        let maker = self.order_maker(signature_type)?;
+
+        let order_type = request.order_type.clone().unwrap_or(OrderType::GTC);
+        let mut expiration = match (request.expiration, request.expires_in) {
+            (Some(expiration), _) => expiration,
+            (None, Some(lifetime)) => self.expiration_after(lifetime)?,
+            (None, None) => DateTime::<Utc>::UNIX_EPOCH,
+        };
+        let derived_nonce = self.derived_nonce.filter(|_| self.use_derived_nonce);
+        let nonce = request.nonce.or(derived_nonce).unwrap_or(0);
+        let taker = request.taker.unwrap_or(Address::ZERO);
+        let post_only = request.post_only.unwrap_or(false);
+
+        if let OrderType::Unknown(other) = &order_type {
+            return Err(Error::validation(format!(
+                "Unsupported order type {other}; expected one of: GTC|GTD|FOK|FAK"
+            )));
+        }
+        if !matches!(order_type, OrderType::GTD) && expiration > DateTime::<Utc>::UNIX_EPOCH {
+            return Err(Error::validation(
+                "Only GTD orders may have a non-zero expiration",
+            ));
+        }
+        if matches!(order_type, OrderType::GTD) && expiration == DateTime::<Utc>::UNIX_EPOCH {
+            return Err(Error::validation("GTD orders require an expiration"));
+        }
+        if matches!(order_type, OrderType::GTD) {
+            let (now, _) = self.policy_now();
+            let earliest = now + GTD_EXPIRATION_BUFFER_SECS + 1;
+            if expiration.timestamp() < earliest {
+                match self.policies.gtd_expiration {
+                    GtdExpiration::Reject => {
+                        return Err(Error::validation(format!(
+                            "GTD expiration {expiration} must be more than \
+                            {GTD_EXPIRATION_BUFFER_SECS} seconds in the future"
+                        )));
+                    }
+                    GtdExpiration::BumpForward => {
+                        expiration = DateTime::<Utc>::from_timestamp(earliest, 0).ok_or(
+                            Error::validation(format!(
+                                "Invalid GTD expiration timestamp {earliest}"
+                            )),
+                        )?;
+                    }
+                }
+            }
+        }
+        if taker != Address::ZERO && !matches!(order_type, OrderType::GTC | OrderType::GTD) {
+            return Err(Error::validation(
+                "A non-zero taker is only supported for GTC and GTD orders",
+            ));
+        }
+        if post_only && !matches!(order_type, OrderType::GTC | OrderType::GTD) {
+            return Err(Error::validation(
+                "postOnly is only supported for GTC and GTD orders",
+            ));
+        }
+
+        let price = request.price;
+        let size = request.size;
+        let side = request.side;
+
+        if price.is_sign_negative() {
+            return Err(Error::validation(format!(
+                "Unable to build Order due to negative price {price}"
+            )));
+        }
+        if size.is_zero() || size.is_sign_negative() {
+            return Err(Error::validation(format!(
+                "Unable to build Order due to negative size {size}"
+            )));
+        }
+        let lot_size_scale = self.policies.lot_size_scale;
+        if size.scale() > lot_size_scale {
+            return Err(Error::validation(format!(
+                "Unable to build Order: Size {size} has {} decimal places. Maximum lot size is {lot_size_scale}",
+                size.scale()
+            )));
+        }
+
+        let minimum_tick_size = tick_size.as_decimal();
+        let decimals = minimum_tick_size.scale();
+
+        if price.scale() > minimum_tick_size.scale() {
+            return Err(Error::validation(format!(
+                "Unable to build Order: Price {price} has {} decimal places. Minimum tick size \
+                {minimum_tick_size} has {} decimal places. Price decimal places <= minimum tick size decimal places",
+                price.scale(),
+                minimum_tick_size.scale()
+            )));
+        }
+        if price < minimum_tick_size {
+            return Err(Error::validation(format!(
+                "Price {price} is below the minimum tradeable price {minimum_tick_size}"
+            )));
+        }
+        let maximum_price = Decimal::ONE - minimum_tick_size;
+        if price > maximum_price {
+            return Err(Error::validation(format!(
+                "Price {price} is above the maximum tradeable price {maximum_price}"
+            )));
+        }
+
+        let notional = match self.policies.notional_rounding {
+            NotionalRounding::SideAware => {
+                let scale = match side {
+                    Side::Buy => BUY_NOTIONAL_DECIMALS,
+                    _ => SELL_NOTIONAL_DECIMALS,
+                };
+                (size * price).round_dp_with_strategy(scale, RoundingStrategy::ToZero)
+            }
+            NotionalRounding::Truncate => {
+                (size * price).trunc_with_scale(decimals + lot_size_scale)
+            }
+        };
+        if notional.is_zero() {
+            return Err(Error::validation(format!(
+                "Unable to build Order: value {} of size {size} at price {price} rounds to zero",
+                size * price
+            )));
+        }
+        self.check_order_limits(size, notional)?;
+        let (taker_amount, maker_amount) = match side {
+            Side::Buy => (size, notional),
+            Side::Sell => (notional, size),
+            other => return Err(Error::validation(format!("Invalid side: {other}"))),
+        };
+
+        let expiration_u64 = expiration
+            .timestamp()
+            .to_u64()
+            .ok_or(Error::validation(format!(
+                "Unable to represent expiration {expiration} as a u64"
+            )))?;
+
+        Ok(PreparedOrder {
+            maker,
+            signature_type,
+            neg_risk,
+            fee_rate_bps,
+            order_type,
+            expiration: expiration_u64,
+            nonce,
+            taker,
+            post_only,
+            maker_amount: U256::from(to_fixed_u128(maker_amount)?),
+            taker_amount: U256::from(to_fixed_u128(taker_amount)?),
+        })
+    }
+
+    /// Returns the exchange contract orders are signed for and settled on, which differs for
+    /// neg-risk markets.
+    pub fn exchange_for(&self, neg_risk: bool) -> Result<Address> {
+        exchange_contract(self.chain_id, neg_risk)
+    }
+
+    /// Returns the EIP-712 hash that [`Self::sign_limit_order`] signs for `order`.
+    ///
+    /// Useful for cross-checking signatures produced by other clients against the same
+    /// exchange domain.
+    #[must_use]
+    pub fn order_signing_hash(&self, order: &Order, neg_risk: bool) -> B256 {
+        order.eip712_signing_hash(self.domain_for(neg_risk))
+    }
+
+    /// Returns the EIP-712 domain separator of the exchange domain
+    /// [`Self::sign_limit_order`] signs against, for cross-checking with the exchange
+    /// contract's on-chain `domainSeparator()`.
+    #[must_use]
+    pub fn domain_separator(&self, neg_risk: bool) -> B256 {
+        self.domain_for(neg_risk).separator()
+    }
+
+    /// Posts an already-signed order to `/order`.
+    ///
+    /// With a `deadline`, the HTTP call is abandoned once it elapses and a
+    /// [`Kind::Timeout`](ErrorKind::Timeout) error is returned, so a quote that can't be
+    /// sent in time is dropped instead of arriving stale. The order may still have reached
+    /// the exchange, so reconcile before reposting it. Waiting for an in-flight slot counts
+    /// against the deadline.
+    pub async fn post_signed_order(
+        &self,
+        signed_order: SignedOrder,
+        timestamp_override: Option<Timestamp>,
+        deadline: Option<Duration>,
+    ) -> Result<PostOrderResponse> {
+        let post = async {
+            let _permit = self.acquire_order_permit().await?;
+            self.post_order_body(&signed_order, timestamp_override).await
+        };
+        match deadline {
+            Some(deadline) => tokio::time::timeout(deadline, post)
+                .await
+                .map_err(|_elapsed| Error::timeout(deadline))?,
+            None => post.await,
+        }
+    }
+
+    /// Posts an already-signed order to `/order`, also returning the exact body bytes that
+    /// were signed into the L2 headers and sent to the exchange.
+    ///
+    /// The bytes deserialize back into the submitted [`SignedOrder`], so they can be stored
+    /// as an audit record of what the exchange received.
+    pub async fn post_signed_order_with_body(
+        &self,
+        signed_order: &SignedOrder,
+        timestamp_override: Option<Timestamp>,
+    ) -> Result<(PostOrderResponse, Vec<u8>)> {
+        // Same serializer `RequestBuilder::json` uses, so these are the bytes that get sent.
+        let body = serde_json::to_vec(signed_order)?;
+        let _permit = self.acquire_order_permit().await?;
+        let response = self
+            .post_order_body(signed_order, timestamp_override)
+            .await?;
+
+        Ok((response, body))
+    }
+
+    /// Submits a pre-serialized order body, attaching L2 headers signed over it.
+    ///
+    /// The body is sent as-is: it must already carry the signed order, `orderType` and
+    /// `owner` fields expected by `POST /order`.
+    pub async fn post_raw_order(
+        &self,
+        body: serde_json::Value,
+        timestamp_override: Option<Timestamp>,
+    ) -> Result<PostOrderResponse> {
+        let _permit = self.acquire_order_permit().await?;
+        self.post_order_body(&body, timestamp_override).await
+    }
+
+    async fn create_or_derive_api_key(
+        client: &ReqwestClient,
+        host: &Url,
+        signer: &PrivateKeySigner,
+        chain_id: ChainId,
+        nonce: Option<u32>,
+        mode: CredentialMode,
+        time_policy: TimePolicy,
+    ) -> Result<(Credentials, CredentialsOrigin, Option<u64>)> {
+        if mode == CredentialMode::DeriveOnly {
+            let derived =
+                Self::derive_api_key(client, host, signer, chain_id, nonce, time_policy).await?;
+            return Ok((
+                derived.credentials,
+                CredentialsOrigin::Derived,
+                derived.nonce,
+            ));
+        }
+
+        match Self::create_api_key(client, host, signer, chain_id, nonce, time_policy).await {
+            Ok(creds) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(address = %signer.address(), "created new api key");
+
+                Ok((creds, CredentialsOrigin::Created, None))
+            }
+            Err(err)
+                if mode == CredentialMode::CreateOrDerive && err.kind() == ErrorKind::Status =>
+            {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    address = %signer.address(),
+                    error = %err,
+                    "api key creation rejected, deriving existing api key"
+                );
+
+                let derived =
+                    Self::derive_api_key(client, host, signer, chain_id, nonce, time_policy)
+                        .await?;
+                Ok((
+                    derived.credentials,
+                    CredentialsOrigin::Derived,
+                    derived.nonce,
+                ))
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn create_api_key(
+        client: &ReqwestClient,
+        host: &Url,
+        signer: &PrivateKeySigner,
+        chain_id: ChainId,
+        nonce: Option<u32>,
+        time_policy: TimePolicy,
+    ) -> Result<Credentials> {
+        let request = client
+            .request(Method::POST, host.join("auth/api-key")?)
+            .build()?;
+        let headers =
+            Self::create_l1_headers(signer, chain_id, nonce, time_policy, host, client).await?;
+
+        crate::request::<Credentials>(client, request, Some(headers)).await
+    }
+
+    async fn derive_api_key(
+        client: &ReqwestClient,
+        host: &Url,
+        signer: &PrivateKeySigner,
+        chain_id: ChainId,
+        nonce: Option<u32>,
+        time_policy: TimePolicy,
+    ) -> Result<DerivedApiKey> {
+        let request = client
+            .request(Method::GET, host.join("auth/derive-api-key")?)
+            .build()?;
+        let headers =
+            Self::create_l1_headers(signer, chain_id, nonce, time_policy, host, client).await?;
+
+        crate::request::<DerivedApiKey>(client, request, Some(headers)).await
+    }
+
+    async fn create_l1_headers(
+        signer: &PrivateKeySigner,
+        chain_id: ChainId,
+        nonce: Option<u32>,
+        time_policy: TimePolicy,
+        host: &Url,
+        client: &ReqwestClient,
+    ) -> Result<HeaderMap> {
+        // L1 auth runs before a client, and its cached offset, exists, so read `/time` directly.
+        let timestamp = match time_policy {
+            TimePolicy::FetchAndCache => {
+                let request = client.request(Method::GET, host.join("time")?).build()?;
+                crate::request::<Timestamp>(client, request, None).await?
+            }
+            TimePolicy::Fixed => Utc::now().timestamp(),
+        };
+        auth::l1::create_headers(signer, chain_id, timestamp, nonce).await
+    }
+
+    async fn create_l2_headers(
+        &self,
+        request: &reqwest::Request,
+        timestamp_override: Option<Timestamp>,
+    ) -> Result<HeaderMap> {
+        let (timestamp, source) = self.l2_timestamp(timestamp_override);
+        self.last_timestamp_source
+            .store(source as u8, Ordering::Relaxed);
+        let Some(cache) = &self.l2_header_cache else {
+            return auth::l2::create_headers(&self.state, request, timestamp).await;
+        };
+
+        let key = L2HeaderKey::new(self.credentials.key(), request, timestamp);
+        if let Some(headers) = cache.get(&key) {
+            return Ok(headers.clone());
+        }
+
+        let headers = auth::l2::create_headers(&self.state, request, timestamp).await?;
+        // Entries from earlier seconds can never hit again.
+        cache.retain(|cached, _| cached.timestamp == timestamp);
+        cache.insert(key, headers.clone());
+
+        Ok(headers)
+    }
+
+    /// Resolves the L2 header timestamp under the configured [`TimePolicy`].
+    fn l2_timestamp(&self, timestamp_override: Option<Timestamp>) -> (Timestamp, TimestampSource) {
+        if let Some(timestamp) = timestamp_override {
+            return (timestamp, TimestampSource::Override);
+        }
+
+        self.policy_now()
+    }
+
+    /// Current unix time per the time policy: corrected by the cached server clock offset
+    /// under [`TimePolicy::FetchAndCache`], local otherwise or until the first sync.
+    fn policy_now(&self) -> (Timestamp, TimestampSource) {
+        match (self.policies.time, self.clock_offset()) {
+            (TimePolicy::FetchAndCache, Some(offset)) => (
+                Utc::now().timestamp() + offset,
+                TimestampSource::ServerOffset,
+            ),
+            _ => (Utc::now().timestamp(), TimestampSource::Local),
+        }
+    }
+
+    /// Returns the expiration `lifetime` from now on the [policy clock](Self::policy_now).
+    fn expiration_after(&self, lifetime: Duration) -> Result<DateTime<Utc>> {
+        let (now, _) = self.policy_now();
+        let expiration = i64::try_from(lifetime.as_secs())
+            .ok()
+            .and_then(|secs| now.checked_add(secs))
+            .and_then(|secs| DateTime::<Utc>::from_timestamp(secs, 0));
+
+        expiration.ok_or(Error::validation(format!(
+            "Unable to represent an expiration {lifetime:?} from now as a timestamp"
+        )))
+    }
+
+    /// Issues an unauthenticated `GET` against `url`, retrying per the read retry policy.
+    async fn get_public<T: DeserializeOwned>(
+        &self,
+        url: Url,
+        query: &[(&str, String)],
+    ) -> Result<T> {
+        with_retries(self.read_retry, || async {
+            let request = self
+                .client
+                .request(Method::GET, url.clone())
+                .query(query)
+                .build()?;
+            crate::request::<T>(&self.client, request, None).await
+        })
+        .await
+    }
+
+    /// Issues an unauthenticated market-data `GET`, mapping "no orderbook" responses to `None`.
+    async fn get_market_data<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+    ) -> Result<Option<T>> {
+        match self.get_public::<T>(self.data_endpoint(path)?, query).await {
+            Ok(response) => Ok(Some(response)),
+            Err(err) if is_not_found(&err) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Waits for an in-flight order slot, or returns `None` when no cap is configured.
+    ///
+    /// Waiters are served in arrival order, so queued orders go out in submission order.
+    /// The slot frees when the returned permit drops.
+    async fn acquire_order_permit(&self) -> Result<Option<SemaphorePermit<'_>>> {
+        let Some(permits) = &self.order_permits else {
+            return Ok(None);
+        };
+
+        let permit = match self.order_permit_timeout {
+            Some(deadline) => tokio::time::timeout(deadline, permits.acquire())
+                .await
+                .map_err(|_e| Error::queue_timeout(deadline))?,
+            None => permits.acquire().await,
+        };
+        // The semaphore is never closed, so this only fails on a broken invariant.
+        permit
+            .map(Some)
+            .map_err(|e| Error::with_source(ErrorKind::Internal, e))
+    }
+
+    /// Issues an L2-authenticated `GET` against `path`.
+    async fn get_authenticated<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+    ) -> Result<T> {
+        let request = self
+            .client
+            .request(Method::GET, self.endpoint(path)?)
+            .query(query)
+            .build()?;
+        let headers = self.create_l2_headers(&request, None).await?;
+
+        crate::request::<T>(&self.client, request, Some(headers)).await
+    }
+
+    async fn post_order_body<B: Serialize + ?Sized>(
+        &self,
+        body: &B,
+        timestamp_override: Option<Timestamp>,
+    ) -> Result<PostOrderResponse> {
+        let request = self
+            .client
+            .request(Method::POST, self.endpoint("order")?)
+            .json(body)
+            .build()?;
+        let headers = self.create_l2_headers(&request, timestamp_override).await?;
+
+        crate::request::<PostOrderResponse>(&self.client, request, Some(headers))
+            .await
+            .map_err(Error::into_field_validation)
+    }
+
+    /// Submits one `POST /orders` request for `signed_orders`, returning the raw response
+    /// entries so a single malformed entry can't sink the batch.
+    async fn post_order_batch(
+        &self,
+        signed_orders: &[SignedOrder],
+        timestamp_override: Option<Timestamp>,
+    ) -> Result<Vec<serde_json::Value>> {
+        let request = self
+            .client
+            .request(Method::POST, self.endpoint("orders")?)
+            .json(signed_orders)
+            .build()?;
+        let headers = self.create_l2_headers(&request, timestamp_override).await?;
+
+        crate::request::<Vec<serde_json::Value>>(&self.client, request, Some(headers)).await
+    }
+
+    /// Overwrites the sides of the cached BBO for `token_id` that are `Some`.
+    #[cfg(feature = "ws")]
+    fn update_live_bbo(
+        &self,
+        token_id: U256,
+        best_bid: Option<Decimal>,
+        best_ask: Option<Decimal>,
+    ) {
+        if best_bid.is_none() && best_ask.is_none() {
+            return;
+        }
+
+        let mut bbo = self.live_bbos.entry(token_id).or_default();
+        if best_bid.is_some() {
+            bbo.best_bid = best_bid;
+        }
+        if best_ask.is_some() {
+            bbo.best_ask = best_ask;
+        }
+    }
+
+    /// Cancels every tracked order, estimating the freed collateral from the tracked requests
+    /// when the server doesn't report it.
+    async fn cancel_tracked_with_freed_collateral(&self) -> Result<CancelOrdersResponse> {
+        if self.tracked_orders.is_none() {
+            return Err(Error::validation(
+                "Order tracking is disabled; pass the orders to cancel",
+            ));
+        }
+        let tracked = self.tracked_orders();
+        if tracked.is_empty() {
+            return Ok(CancelOrdersResponse {
+                freed_collateral: Some(Decimal::ZERO),
+                ..CancelOrdersResponse::default()
+            });
+        }
+
+        let order_ids: Vec<String> = tracked.keys().cloned().collect();
+        let mut response = self.cancel_orders(&order_ids).await?;
+        if response.freed_collateral.is_none() {
+            let freed = response
+                .canceled
+                .iter()
+                .filter_map(|order_id| tracked.get(order_id))
+                .filter(|request| request.side == Side::Buy)
+                .map(|request| request.size * request.price)
+                .sum();
+            response.freed_collateral = Some(freed);
+        }
+
+        Ok(response)
+    }
+
+    /// Sends a cancel `body` to `path`, untracking `order_ids` for the duration of the
+    /// request and restoring those the cancel didn't remove.
+    async fn cancel_tracked<B: Serialize + ?Sized, S: AsRef<str>>(
+        &self,
+        path: &str,
+        body: &B,
+        order_ids: &[S],
+    ) -> Result<CancelOrdersResponse> {
+        let untracked: Vec<_> = self
+            .tracked_orders
+            .as_ref()
+            .map(|orders| {
+                order_ids
+                    .iter()
+                    .filter_map(|id| orders.remove(id.as_ref()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let result = async {
+            let request = self
+                .client
+                .request(Method::DELETE, self.endpoint(path)?)
+                .json(body)
+                .build()?;
+            let headers = self.create_l2_headers(&request, None).await?;
+
+            crate::request::<CancelOrdersResponse>(&self.client, request, Some(headers)).await
+        }
+        .await;
+
+        if let Some(orders) = &self.tracked_orders {
+            for (order_id, request) in untracked {
+                let still_resting = match &result {
+                    Ok(response) => response.not_canceled.contains_key(&order_id),
+                    Err(_) => true,
+                };
+                if still_resting {
+                    orders.insert(order_id, request);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Checks an order's size and notional against the configured per-order caps and
+    /// [`HotPathPolicies::min_order_value`].
+    ///
+    /// Together with [`Self::check_max_position`] and [`Self::check_market_order_size`] this
+    /// is every size guard the client applies before signing.
+    fn check_order_limits(&self, size: Decimal, notional: Decimal) -> Result<()> {
+        if let Some(max_order_size) = self.max_order_size
+            && size > max_order_size
+        {
+            return Err(Error::validation(format!(
+                "Order size {size} exceeds the maximum order size {max_order_size}"
+            )));
+        }
+        if let Some(min_order_value) = self.policies.min_order_value
+            && notional < min_order_value
+        {
+            return Err(Error::validation(format!(
+                "Order value {notional} is below the minimum order value {min_order_value}"
+            )));
+        }
+        if let Some(max_order_notional) = self.max_order_notional
+            && notional > max_order_notional
+        {
+            return Err(Error::validation(format!(
+                "Order value {notional} exceeds the maximum order value {max_order_notional}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Fetches the position checked against `max_position` when a BUY's token has none
+    /// cached.
+    async fn fetch_position_on_miss(&self, request: &LimitOrderRequest) -> Result<()> {
+        if self.max_position.is_some()
+            && request.side == Side::Buy
+            && !self.positions.contains_key(&request.token_id)
+        {
+            self.position(request.token_id).await?;
+        }
+        Ok(())
+    }
+
+    /// Returns the cached position a BUY `request` is checked against, or `None` when
+    /// `max_position` doesn't apply to it.
+    fn cached_position(&self, request: &LimitOrderRequest) -> Result<Option<Decimal>> {
+        if self.max_position.is_none() || request.side != Side::Buy {
+            return Ok(None);
+        }
+
+        self.positions
+            .get(&request.token_id)
+            .map(|size| Some(*size))
+            .ok_or_else(|| {
+                Error::validation(format!(
+                    "No position cached for token {} to check against the maximum position",
+                    request.token_id
+                ))
+            })
+    }
+
+    /// Rejects a BUY `request` that would take `current` above `max_position`.
+    fn check_max_position(&self, request: &LimitOrderRequest, current: Decimal) -> Result<()> {
+        let Some(max_position) = self.max_position else {
+            return Ok(());
+        };
+
+        let projected = current + request.size;
+        if projected > max_position {
+            return Err(Error::validation(format!(
+                "Order would take the position in token {} to {projected}, above the maximum position {max_position}",
+                request.token_id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Adds a signed BUY `request` to its cached position, re-checking the cap under the
+    /// entry's lock so concurrent orders can't overshoot it together.
+    fn reserve_position(&self, request: &LimitOrderRequest) -> Result<()> {
+        if self.cached_position(request)?.is_none() {
+            return Ok(());
+        }
+        let Some(mut position) = self.positions.get_mut(&request.token_id) else {
+            return Ok(());
+        };
+
+        self.check_max_position(request, *position)?;
+        *position += request.size;
+        Ok(())
+    }
+
+    /// Fetches the bounds checked by `market_order_size_limits` when `request`'s token has
+    /// none cached.
+    async fn fetch_order_size_limits_on_miss(&self, request: &LimitOrderRequest) -> Result<()> {
+        if self.market_order_size_limits && !self.order_size_limits.contains_key(&request.token_id)
+        {
+            self.order_size_limits(request.token_id).await?;
+        }
+        Ok(())
+    }
+
+    /// Rejects a `request` whose size is outside the cached bounds published by its market.
+    fn check_market_order_size(&self, request: &LimitOrderRequest) -> Result<()> {
+        if !self.market_order_size_limits {
+            return Ok(());
+        }
+
+        let limits = self
+            .order_size_limits
+            .get(&request.token_id)
+            .map(|limits| *limits)
+            .ok_or_else(|| {
+                Error::validation(format!(
+                    "No order size limits cached for token {}",
+                    request.token_id
+                ))
+            })?;
+        let size = request.size;
+        if size < limits.min {
+            return Err(Error::validation(format!(
+                "Order size {size} is below the market's minimum order size {}",
+                limits.min
+            )));
+        }
+        if let Some(max) = limits.max
+            && size > max
+        {
+            return Err(Error::validation(format!(
+                "Order size {size} exceeds the market's maximum order size {max}"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Records `request` in [`Self::tracked_orders`] when `result` shows it resting on the
+    /// book.
+    fn track_order(&self, request: &LimitOrderRequest, result: &Result<PostOrderResponse>) {
+        let Some(orders) = &self.tracked_orders else {
+            return;
+        };
+        if let Ok(response) = result
+            && response.success
+            && response.status == OrderStatusType::Live
+            && !response.order_id.is_empty()
+        {
+            orders.insert(response.order_id.clone(), request.clone());
+        }
+    }
+
+    /// Collects `data` from every page of an authenticated, cursor-paginated endpoint.
+    async fn get_all_pages<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+    ) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let mut query = query.to_vec();
+            if let Some(cursor) = cursor.take() {
+                query.push(("next_cursor", cursor));
+            }
+
+            let page = self.get_authenticated::<Page<T>>(path, &query).await?;
+            items.extend(page.data);
+
+            if page.next_cursor == TERMINAL_CURSOR || page.next_cursor.is_empty() {
+                break;
+            }
+            cursor = Some(page.next_cursor);
+        }
+
+        Ok(items)
+    }
+
+    fn endpoint(&self, path: &str) -> Result<Url> {
+        Ok(self.host.join(path)?)
+    }
+
+    /// Resolves a market-data `path` against the data host, falling back to the order host.
+    fn data_endpoint(&self, path: &str) -> Result<Url> {
+        Ok(self.data_host.as_ref().unwrap_or(&self.host).join(path)?)
+    }
+
+    fn domain_for(&self, neg_risk: bool) -> &Eip712Domain {
+        if neg_risk {
+            &self.neg_risk_domain
+        } else {
+            &self.domain
+        }
+    }
+
+    /// Returns the maker of an order signed with `signature_type`: the signer itself for
+    /// [`SignatureType::Eoa`], otherwise the funder, which must satisfy that type's constraints.
+    fn order_maker(&self, signature_type: SignatureType) -> Result<Address> {
+        if signature_type == SignatureType::Eoa {
+            return Ok(self.address());
+        }
+        if signature_type != self.signature_type {
+            Self::validate_funder_signature(
+                signature_type,
+                self.funder,
+                self.address(),
+                self.chain_id,
+            )?;
+        }
+        Ok(self.funder)
+    }
+
+    fn validate_funder_signature(
+        signature_type: SignatureType,
+        funder: Address,
+        signer: Address,
+        chain_id: ChainId,
+    ) -> Result<()> {
+        if matches!(signature_type, SignatureType::Eoa) {
+            return Err(Error::validation(
+                "Cannot have a funder address with an Eoa signature type",
+            ));
+        }
+        if funder == Address::ZERO {
+            return Err(Error::validation(
+                "Cannot have a zero funder address with a proxy signature type",
+            ));
+        }
+        // The exchange verifies Safe orders by recovering the EOA from a plain EIP-712
+        // signature and requiring `maker` to be the Safe that EOA owns via the Polymarket
+        // Safe factory. Any other funder produces orders that are rejected on-chain.
+        if signature_type == SignatureType::GnosisSafe {
+            let safe = derive_safe_wallet(signer, chain_id).ok_or(Error::validation(format!(
+                "Safe wallet derivation not supported on chain {chain_id}"
+            )))?;
+            if funder != safe {
+                return Err(Error::validation(format!(
+                    "GnosisSafe funder {funder} is not the Safe {safe} owned by signer {signer}"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Rejects a funder without contract code at `rpc_url`: Proxy and `GnosisSafe` funders are
+    /// wallet contracts, and orders funded by an EOA fail on-chain signature verification.
+    async fn validate_funder_code(
+        client: &ReqwestClient,
+        rpc_url: &Url,
+        funder: Address,
+    ) -> Result<()> {
+        let request = client
+            .request(Method::POST, rpc_url.clone())
+            .json(&serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "eth_getCode",
+                "params": [funder, "latest"],
+            }))
+            .build()?;
+        let response = crate::request::<serde_json::Value>(client, request, None).await?;
+
+        let Some(code) = response.get("result").and_then(serde_json::Value::as_str) else {
+            return Err(Error::validation(format!(
+                "eth_getCode for funder {funder} failed: {}",
+                response.get("error").unwrap_or(&response)
+            )));
+        };
+        if code.trim_start_matches("0x").is_empty() {
+            return Err(Error::validation(format!(
+                "Funder {funder} has no contract code; Proxy and GnosisSafe funders must be \
+                 deployed wallet contracts, not EOAs"
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Returns the exchange contract selected by `neg_risk` on `chain_id`.
+fn exchange_contract(chain_id: ChainId, neg_risk: bool) -> Result<Address> {
+    Ok(contract_config(chain_id, neg_risk)
+        .ok_or(Error::missing_contract_config(chain_id, neg_risk))?
+        .exchange)
+}
+
+/// Logs a single structured line for `request` when `result` is a rejection: a failed call,
+/// or a response with `success: false`.
+#[cfg(feature = "tracing")]
+fn log_rejection(request: &LimitOrderRequest, result: &Result<PostOrderResponse>) {
+    let reason = match result {
+        Ok(response) if response.success => return,
+        Ok(response) => response.error_msg.clone().unwrap_or_default(),
+        Err(err) => rejection_reason(err),
+    };
+
+    tracing::warn!(
+        token_id = %request.token_id,
+        side = %request.side,
+        price = %request.price,
+        size = %request.size,
+        reason = %reason,
+        "order rejected"
+    );
+}
+
+/// Extracts the human-readable reason from an order rejection, unwrapping the
+/// `{"error": ...}` body the CLOB returns on non-success statuses.
+#[cfg(feature = "tracing")]
+fn rejection_reason(err: &Error) -> String {
+    if let Some(status) = err.downcast_ref::<Status>() {
+        return serde_json::from_str::<serde_json::Value>(&status.message)
+            .ok()
+            .and_then(|body| body.get("error")?.as_str().map(str::to_owned))
+            .unwrap_or_else(|| status.message.clone());
+    }
+    if let Some(validation) = err.downcast_ref::<Validation>() {
+        return validation.reason.clone();
+    }
+    if let Some(field_validation) = err.downcast_ref::<FieldValidation>() {
+        return field_validation.message.clone();
+    }
+    err.to_string()
+}
+
+fn binary_token_ids(market: &MarketResponse) -> Result<[U256; 2]> {
+    let [first, second] = market.tokens.as_slice() else {
+        return Err(Error::validation(format!(
+            "expected 2 outcome tokens for market {:?}, got {}",
+            market.condition_id,
+            market.tokens.len()
+        )));
+    };
+
+    if first.outcome.eq_ignore_ascii_case("no") && second.outcome.eq_ignore_ascii_case("yes") {
+        Ok([second.token_id, first.token_id])
+    } else {
+        Ok([first.token_id, second.token_id])
+    }
+}
+
+/// Runs `call`, rerunning it per `policy` while it fails with a
+/// [retryable](Error::is_retryable) error.
+async fn with_retries<T, F, Fut>(policy: RetryPolicy, mut call: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut attempt = 0;
+    loop {
+        match call().await {
+            Err(err) if attempt < policy.max_retries && err.is_retryable() => {
+                tokio::time::sleep(policy.backoff(attempt)).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// The CLOB answers `404` for tokens that have no orderbook yet.
+fn is_not_found(err: &Error) -> bool {
+    err.downcast_ref::<Status>()
+        .is_some_and(|status| status.status_code == StatusCode::NOT_FOUND)
+}
+
+/// Collateral locked by the unfilled part of the BUY orders among `orders` that were
+/// `canceled`.
+fn freed_collateral(orders: &[OpenOrderResponse], canceled: &[String]) -> Decimal {
+    orders
+        .iter()
+        .filter(|order| order.side == Side::Buy && canceled.contains(&order.id))
+        .map(|order| (order.original_size - order.size_matched).max(Decimal::ZERO) * order.price)
+        .sum()
+}
+
+/// Copies a failed batch request's error for each of its orders, keeping HTTP statuses so
+/// [`Error::is_retryable`] and [`Error::status_code`] still apply per order.
+fn batch_error(err: &Error) -> Error {
+    match err.downcast_ref::<Status>() {
+        Some(status) => Error::status(
+            status.status_code,
+            status.method.clone(),
+            status.path.clone(),
+            status.message.clone(),
+        ),
+        None => Error::validation(format!("batch request failed: {err}")),
+    }
+}
+
+/// Whether `err` is the CLOB rejecting the request's credentials.
+fn is_auth_rejection(err: &Error) -> bool {
+    err.downcast_ref::<Status>().is_some_and(|status| {
+        matches!(
+            status.status_code,
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+        )
+    })
+}
+
+/// Removes trailing zeros, truncates to 6 decimals, and quantizes as integer.
+fn to_fixed_u128(d: Decimal) -> Result<u128> {
+    if d.is_sign_negative() {
+        return Err(Error::validation(format!("amount cannot be negative: {d}")));
+    }
+
+    d.normalize()
+        .trunc_with_scale(USDC_DECIMALS)
+        .mantissa()
+        .to_u128()
+        .ok_or(Error::validation(format!(
+            "unable to represent amount as u128: {d}"
+        )))
+}
+
+/// Mask salt to <= 2^53 - 1 because backend parses as IEEE 754.
+fn to_ieee_754_int(salt: u64) -> u64 {
+    salt & ((1 << 53) - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicU64;
+
+    use alloy::sol_types::SolStruct as _;
+    use rust_decimal_macros::dec;
+    use secrecy::SecretString;
+
+    use super::*;
+    use crate::POLYGON;
+    use crate::auth::Uuid;
+    use crate::clob::types::TickSize;
+    use crate::error::Validation;
+    use crate::hotpath::FixedOrFetch;
+    use crate::types::{address, b256};
+
+    // publicly known private key
+    const PRIVATE_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+    fn client() -> HotPathClient {
+        client_with(SignatureType::Proxy, Address::repeat_byte(0x11)).unwrap()
+    }
+
+    fn client_with(signature_type: SignatureType, funder: Address) -> Result<HotPathClient> {
+        client_with_policies(
+            signature_type,
+            funder,
+            HotPathPolicies::fixed(TickSize::Hundredth, false, 0),
+        )
+    }
+
+    fn client_with_policies(
+        signature_type: SignatureType,
+        funder: Address,
+        policies: HotPathPolicies,
+    ) -> Result<HotPathClient> {
+        client_from(config_with(signature_type, funder, policies)?)
+    }
+
+    fn config() -> HotPathConfig {
+        config_with(
+            SignatureType::Proxy,
+            Address::repeat_byte(0x11),
+            HotPathPolicies::fixed(TickSize::Hundredth, false, 0),
+        )
+        .unwrap()
+    }
+
+    fn config_with(
+        signature_type: SignatureType,
+        funder: Address,
+        policies: HotPathPolicies,
+    ) -> Result<HotPathConfig> {
+        HotPathConfig::new(
+            Url::parse("https://clob.polymarket.com").unwrap(),
+            POLYGON,
+            SecretString::from(PRIVATE_KEY.to_owned()),
+            signature_type,
+            funder,
+            None,
+            policies,
+        )
+    }
+
+    fn client_from(config: HotPathConfig) -> Result<HotPathClient> {
+        let credentials = Credentials::new(Uuid::nil(), String::new(), String::new());
+
+        HotPathClient::with_credentials(config, credentials)
+    }
+
+    #[test]
+    fn policies_should_return_configured_policies() {
+        let mut client = client();
+        client.policies.time = TimePolicy::FetchAndCache;
+
+        let policies = client.policies();
+
+        assert!(
+            matches!(policies.tick_size, FixedOrFetch::Fixed(TickSize::Hundredth)),
+            "unexpected tick size policy: {:?}",
+            policies.tick_size
+        );
+        assert!(
+            matches!(policies.neg_risk, FixedOrFetch::Fixed(false)),
+            "unexpected neg risk policy: {:?}",
+            policies.neg_risk
+        );
+        assert!(
+            matches!(policies.fee_rate_bps, FixedOrFetch::Fixed(0)),
+            "unexpected fee rate policy: {:?}",
+            policies.fee_rate_bps
+        );
+        assert!(
+            matches!(policies.time, TimePolicy::FetchAndCache),
+            "unexpected time policy: {:?}",
+            policies.time
+        );
+    }
+
+    #[test]
+    fn funder_and_signer_address_should_return_configured_values() {
+        let client = client();
+
+        assert_eq!(client.funder(), Address::repeat_byte(0x11));
+        assert_eq!(
+            client.signer_address(),
+            address!("0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266")
+        );
+        assert_eq!(client.signer_address(), client.address());
+    }
+
+    #[test]
+    fn timestamp_within_tolerance_should_be_unknown_before_sync() {
+        let client = client();
+
+        assert_eq!(client.clock_offset(), None);
+        assert_eq!(client.timestamp_within_tolerance(AuthLayer::L1), None);
+    }
+
+    #[test]
+    fn timestamp_within_tolerance_should_apply_per_layer() {
+        let mut client = client();
+        client.clock_tolerance = ClockTolerance {
+            l1_secs: 60,
+            l2_secs: 10,
+        };
+        client.clock_offset.store(30, Ordering::Relaxed);
+
+        assert_eq!(client.timestamp_within_tolerance(AuthLayer::L1), Some(true));
+        assert_eq!(client.timestamp_within_tolerance(AuthLayer::L2), Some(false));
+
+        client.clock_offset.store(-5, Ordering::Relaxed);
+
+        assert_eq!(client.timestamp_within_tolerance(AuthLayer::L2), Some(true));
+    }
+
+    #[test]
+    fn timestamp_within_tolerance_should_follow_each_layer_clock() {
+        let mut client = client();
+        client.clock_tolerance = ClockTolerance {
+            l1_secs: 10,
+            l2_secs: 10,
+        };
+        client.clock_offset.store(30, Ordering::Relaxed);
+
+        for layer in [AuthLayer::L1, AuthLayer::L2] {
+            assert_eq!(
+                client.timestamp_within_tolerance(layer),
+                Some(false),
+                "{layer:?} should use the local clock under TimePolicy::Fixed"
+            );
+        }
+
+        client.policies.time = TimePolicy::FetchAndCache;
+
+        for layer in [AuthLayer::L1, AuthLayer::L2] {
+            assert_eq!(
+                client.timestamp_within_tolerance(layer),
+                Some(true),
+                "{layer:?} should follow server time under TimePolicy::FetchAndCache"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_gnosis_safe_should_succeed() {
+        let signer = PrivateKeySigner::from_str(PRIVATE_KEY).unwrap().address();
+        let safe = derive_safe_wallet(signer, POLYGON).unwrap();
+        let client = client_with(SignatureType::GnosisSafe, safe).unwrap();
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.45), dec!(10));
+
+        let signed = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap();
+
+        assert_eq!(signed.order.signatureType, SignatureType::GnosisSafe as u8);
+        assert_eq!(signed.order.maker, safe);
+        assert_eq!(signed.order.signer, signer);
+        assert_eq!(signed.signature.as_bytes().len(), 65);
+
+        let hash = signed
+            .order
+            .eip712_signing_hash(&order_domain(POLYGON, false).unwrap());
+        let recovered = signed
+            .signature
+            .recover_address_from_prehash(&hash)
+            .unwrap();
+        assert_eq!(recovered, signer);
+    }
+
+    #[derive(Debug, Default)]
+    struct CountingSalt(AtomicU64);
+
+    impl SaltSource for CountingSalt {
+        fn next_salt(&self) -> u64 {
+            self.0.fetch_add(1, Ordering::Relaxed) + 1
+        }
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_should_use_salt_source() {
+        let client = client().with_salt_source(CountingSalt::default());
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.45), dec!(10));
+
+        for expected in 1..=3_u64 {
+            let signed = client
+                .sign_limit_order(&request, LimitOrderOverrides::default())
+                .await
+                .unwrap();
+            assert_eq!(signed.order.salt, U256::from(expected));
+        }
+    }
+
+    #[test]
+    fn order_signing_hash_should_match_known_value() {
+        let client = client();
+        let order = Order {
+            salt: U256::from(1),
+            maker: Address::repeat_byte(0x11),
+            signer: client.address(),
+            taker: Address::ZERO,
+            tokenId: U256::from(1),
+            makerAmount: U256::from(5_553_000_u64),
+            takerAmount: U256::from(12_340_000_u64),
+            expiration: U256::ZERO,
+            nonce: U256::ZERO,
+            feeRateBps: U256::ZERO,
+            side: Side::Buy as u8,
+            signatureType: SignatureType::Proxy as u8,
+        };
+
+        assert_eq!(
+            client.order_signing_hash(&order, false),
+            b256!("e3a237807108af88001b6c20d3189832fdec9682911b3f17fb1108ab1dfce294")
+        );
+        assert_eq!(
+            client.order_signing_hash(&order, true),
+            b256!("2a6ebc26a7722965acf972fbd526715aaa48697d470518a3d448da175e87ecc5")
+        );
+    }
+
+    #[tokio::test]
+    async fn l2_header_cache_hit_should_reuse_signature() -> Result<()> {
+        let mut client = client();
+        let cache = Arc::new(DashMap::new());
+        client.l2_header_cache = Some(Arc::clone(&cache));
+
+        let request = client
+            .client
+            .request(Method::POST, client.endpoint("order")?)
+            .body(r#"{"order":{}}"#)
+            .build()?;
+        let first = client.create_l2_headers(&request, Some(1_000_000)).await?;
+        assert_eq!(cache.len(), 1, "miss should populate the cache");
+
+        // Poison the entry so a hit is distinguishable from re-signing.
+        let key = L2HeaderKey::new(client.credentials.key(), &request, 1_000_000);
+        let mut cached = first.clone();
+        cached.insert(auth::l2::POLY_SIGNATURE, "cached".parse().unwrap());
+        cache.insert(key, cached.clone());
+
+        let second = client.create_l2_headers(&request, Some(1_000_000)).await?;
+        assert_eq!(second, cached);
+
+        let third = client.create_l2_headers(&request, Some(1_000_001)).await?;
+        assert_ne!(
+            third.get(auth::l2::POLY_SIGNATURE),
+            cached.get(auth::l2::POLY_SIGNATURE)
+        );
+        assert_eq!(cache.len(), 1, "stale timestamps should be evicted");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn last_timestamp_source_should_track_l2_headers() -> Result<()> {
+        let mut client = client();
+        client.policies.time = TimePolicy::FetchAndCache;
+        let request = client
+            .client
+            .request(Method::GET, client.endpoint("data/orders")?)
+            .build()?;
+        assert_eq!(client.last_timestamp_source(), None);
+
+        client.create_l2_headers(&request, None).await?;
+        assert_eq!(
+            client.last_timestamp_source(),
+            Some(TimestampSource::Local),
+            "unsynced clock should fall back to local time"
+        );
+
+        client.clock_offset.store(5, Ordering::Relaxed);
+        client.create_l2_headers(&request, None).await?;
+        assert_eq!(
+            client.last_timestamp_source(),
+            Some(TimestampSource::ServerOffset)
+        );
+
+        client.create_l2_headers(&request, Some(1_000_000)).await?;
+        assert_eq!(
+            client.last_timestamp_source(),
+            Some(TimestampSource::Override)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn gnosis_safe_with_foreign_funder_should_fail() {
+        let err = client_with(SignatureType::GnosisSafe, Address::repeat_byte(0x11)).unwrap_err();
+        let validation = err.downcast_ref::<Validation>().unwrap();
+
+        assert!(
+            validation.reason.starts_with("GnosisSafe funder"),
+            "unexpected reason: {}",
+            validation.reason
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_output_should_be_unchanged() {
+        let client = client();
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.45), dec!(12.34));
+
+        for neg_risk in [false, true] {
+            let overrides = LimitOrderOverrides::default().with_neg_risk(neg_risk);
+            let signed = client.sign_limit_order(&request, overrides).await.unwrap();
+
+            assert_eq!(signed.order.makerAmount, U256::from(5_553_000_u64));
+            assert_eq!(signed.order.takerAmount, U256::from(12_340_000_u64));
+
+            let hash = signed
+                .order
+                .eip712_signing_hash(&order_domain(POLYGON, neg_risk).unwrap());
+            let recovered = signed
+                .signature
+                .recover_address_from_prehash(&hash)
+                .unwrap();
+            assert_eq!(recovered, client.address());
+        }
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_fak_should_succeed() {
+        let client = client();
+        let mut request = LimitOrderRequest::new(U256::from(1), Side::Sell, dec!(0.45), dec!(10));
+        request.order_type = Some(OrderType::FAK);
+
+        let signed = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap();
+        let value = serde_json::to_value(&signed).unwrap();
+
+        assert_eq!(signed.order_type, OrderType::FAK);
+        assert_eq!(value["orderType"], "FAK");
+        assert_eq!(value["postOnly"], false);
+        assert_eq!(value["order"]["expiration"], "0");
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_price_below_minimum_should_fail() {
+        let client = client();
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.00), dec!(10));
+
+        let err = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Price 0.00 is below the minimum tradeable price 0.01"
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_price_above_maximum_should_fail() {
+        let client = client();
+        let request = LimitOrderRequest::new(U256::from(1), Side::Sell, dec!(1.00), dec!(10));
+
+        let err = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Price 1.00 is above the maximum tradeable price 0.99"
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_above_min_order_value_should_succeed() {
+        let mut client = client();
+        client.policies.min_order_value = Some(dec!(1));
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.05), dec!(20));
+
+        client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_below_min_order_value_should_fail() {
+        let mut client = client();
+        client.policies.min_order_value = Some(dec!(1));
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.05), dec!(19.99));
+
+        let err = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Order value 0.9995 is below the minimum order value 1"
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_with_taker_should_sign_private_order() {
+        let client = client();
+        let taker = Address::repeat_byte(0x22);
+        let mut request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.45), dec!(10));
+        request.taker = Some(taker);
+
+        let signed = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap();
+
+        assert_eq!(signed.order.taker, taker);
+        let value = serde_json::to_value(&signed).unwrap();
+        assert_eq!(value["order"]["taker"], taker.to_string());
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_fok_with_taker_should_fail() {
+        let client = client();
+        let mut request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.45), dec!(10));
+        request.order_type = Some(OrderType::FOK);
+        request.taker = Some(Address::repeat_byte(0x22));
+
+        let err = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "A non-zero taker is only supported for GTC and GTD orders"
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_should_reject_size_beyond_lot_size_scale() {
+        let client = client();
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.45), dec!(12.3456));
+
+        let err = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Unable to build Order: Size 12.3456 has 4 decimal places. Maximum lot size is 2"
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_should_honor_custom_lot_size_scale() {
+        let policies = HotPathPolicies::builder()
+            .fixed_tick_size(TickSize::Hundredth)
+            .fixed_neg_risk(false)
+            .fixed_fee_rate_bps(0)
+            .lot_size_scale(4)
+            .build()
+            .unwrap();
+        let client =
+            client_with_policies(SignatureType::Proxy, Address::repeat_byte(0x11), policies)
+                .unwrap();
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.45), dec!(12.3456));
+
+        let signed = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap();
+
+        assert_eq!(signed.order.makerAmount, U256::from(5_555_520_u64));
+        assert_eq!(signed.order.takerAmount, U256::from(12_345_600_u64));
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_should_round_notional_per_policy() {
+        let client_for = |rounding| {
+            let policies = HotPathPolicies::builder()
+                .fixed_tick_size(TickSize::Hundredth)
+                .fixed_neg_risk(false)
+                .fixed_fee_rate_bps(0)
+                .lot_size_scale(6)
+                .notional_rounding(rounding)
+                .build()
+                .unwrap();
+            client_with_policies(SignatureType::Proxy, Address::repeat_byte(0x11), policies)
+                .unwrap()
+        };
+        let buy = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.33), dec!(0.123457));
+        let sell = LimitOrderRequest::new(U256::from(1), Side::Sell, dec!(0.33), dec!(0.123457));
+        let exact = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.33), dec!(10.01));
+        let amounts = |signed: SignedOrder| (signed.order.makerAmount, signed.order.takerAmount);
+
+        // price * size = 0.04074081, beyond the 6 decimals USDC amounts carry.
+        for (rounding, buy_maker_amount, sell_taker_amount, exact_maker_amount) in [
+            (NotionalRounding::Truncate, 40_740_u64, 40_740_u64, 3_303_300_u64),
+            (NotionalRounding::SideAware, 40_000_u64, 40_700_u64, 3_300_000_u64),
+        ] {
+            let client = client_for(rounding);
+
+            let signed = client
+                .sign_limit_order(&buy, LimitOrderOverrides::default())
+                .await
+                .unwrap();
+            assert_eq!(
+                amounts(signed),
+                (U256::from(buy_maker_amount), U256::from(123_457_u64)),
+                "{rounding:?} buy"
+            );
+
+            let signed = client
+                .sign_limit_order(&sell, LimitOrderOverrides::default())
+                .await
+                .unwrap();
+            assert_eq!(
+                amounts(signed),
+                (U256::from(123_457_u64), U256::from(sell_taker_amount)),
+                "{rounding:?} sell"
+            );
+
+            // price * size = 3.3033 fits USDC precision, but not the 2 decimals of a buy.
+            let signed = client
+                .sign_limit_order(&exact, LimitOrderOverrides::default())
+                .await
+                .unwrap();
+            assert_eq!(
+                amounts(signed),
+                (U256::from(exact_maker_amount), U256::from(10_010_000_u64)),
+                "{rounding:?} exact buy"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_side_aware_zero_notional_should_fail() {
+        let policies = HotPathPolicies::builder()
+            .fixed_tick_size(TickSize::Hundredth)
+            .fixed_neg_risk(false)
+            .fixed_fee_rate_bps(0)
+            .notional_rounding(NotionalRounding::SideAware)
+            .build()
+            .unwrap();
+        let client =
+            client_with_policies(SignatureType::Proxy, Address::repeat_byte(0x11), policies)
+                .unwrap();
+        // price * size = 0.005, below the 2 decimals a buy's USDC amount keeps.
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.01), dec!(0.5));
+
+        let err = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Unable to build Order: value 0.005 of size 0.5 at price 0.01 rounds to zero"
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_fak_post_only_should_fail() {
+        let client = client();
+        let mut request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.45), dec!(10));
+        request.order_type = Some(OrderType::FAK);
+        request.post_only = Some(true);
+
+        let err = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "postOnly is only supported for GTC and GTD orders"
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_fak_with_expiration_should_fail() {
+        let client = client();
+        let mut request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.45), dec!(10));
+        request.order_type = Some(OrderType::FAK);
+        request.expiration = DateTime::<Utc>::from_timestamp(Utc::now().timestamp() + 60, 0);
+
+        let err = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Only GTD orders may have a non-zero expiration"
+        );
+    }
+
+    #[test]
+    fn validate_order_should_accept_valid_order() {
+        let client = client();
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.45), dec!(10));
+
+        client
+            .validate_order(&request, LimitOrderOverrides::default())
+            .unwrap();
+    }
+
+    #[test]
+    fn validate_order_should_reject_price_off_tick() {
+        let client = client();
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.455), dec!(10));
+
+        let err = client
+            .validate_order(&request, LimitOrderOverrides::default())
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Unable to build Order: Price 0.455 has 3 decimal places. Minimum tick size 0.01 has 2 \
+            decimal places. Price decimal places <= minimum tick size decimal places"
+        );
+    }
+
+    #[test]
+    fn validate_order_should_honor_tick_size_override() {
+        let client = client();
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.455), dec!(10));
+
+        client
+            .validate_order(
+                &request,
+                LimitOrderOverrides::default().with_tick_size(TickSize::Thousandth),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn validate_order_should_reject_size_beyond_lot_size_scale() {
+        let client = client();
+        let request = LimitOrderRequest::new(U256::from(1), Side::Sell, dec!(0.45), dec!(1.234));
+
+        let err = client
+            .validate_order(&request, LimitOrderOverrides::default())
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Unable to build Order: Size 1.234 has 3 decimal places. Maximum lot size is 2"
+        );
+    }
+
+    #[test]
+    fn validate_order_should_reject_gtc_with_expiration() {
+        let client = client();
+        let mut request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.45), dec!(10));
+        request.expiration = DateTime::<Utc>::from_timestamp(Utc::now().timestamp() + 60, 0);
+
+        let err = client
+            .validate_order(&request, LimitOrderOverrides::default())
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Only GTD orders may have a non-zero expiration"
+        );
+    }
+
+    #[test]
+    fn validate_order_should_reject_fok_post_only() {
+        let client = client();
+        let mut request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.45), dec!(10));
+        request.order_type = Some(OrderType::FOK);
+        request.post_only = Some(true);
+
+        let err = client
+            .validate_order(&request, LimitOrderOverrides::default())
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "postOnly is only supported for GTC and GTD orders"
+        );
+    }
+
+    fn client_with_allowed_tokens(token_ids: &[U256]) -> HotPathClient {
+        client_from(config().with_allowed_token_ids(token_ids.iter().copied())).unwrap()
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_allowed_token_should_succeed() {
+        let client = client_with_allowed_tokens(&[U256::from(1), U256::from(2)]);
+        let request = LimitOrderRequest::new(U256::from(2), Side::Buy, dec!(0.45), dec!(10));
+
+        let signed = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap();
+
+        assert_eq!(signed.order.tokenId, U256::from(2));
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_token_outside_allowlist_should_fail() {
+        let client = client_with_allowed_tokens(&[U256::from(1)]);
+        let request = LimitOrderRequest::new(U256::from(3), Side::Buy, dec!(0.45), dec!(10));
+
+        let err = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Token id 3 is not in the allowed token ids"
+        );
+    }
+
+    fn client_with_caps(max_order_size: Decimal, max_order_notional: Decimal) -> HotPathClient {
+        let config = config()
+            .with_max_order_size(max_order_size)
+            .unwrap()
+            .with_max_order_notional(max_order_notional)
+            .unwrap();
+
+        client_from(config).unwrap()
+    }
+
+    #[test]
+    fn order_caps_should_reject_non_positive_limits() {
+        for limit in [dec!(0), dec!(-1)] {
+            for err in [
+                config().with_max_order_size(limit).unwrap_err(),
+                config().with_max_order_notional(limit).unwrap_err(),
+                config().with_max_position(limit).unwrap_err(),
+            ] {
+                assert_eq!(err.kind(), ErrorKind::Validation);
+            }
+        }
+
+        let err = config().with_max_order_size(dec!(0)).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "max_order_size must be positive, got 0"
+        );
+
+        let mut config = config();
+        config.max_order_notional = Some(dec!(0));
+        let err = client_from(config).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "max_order_notional must be positive, got 0"
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_within_caps_should_succeed() {
+        let client = client_with_caps(dec!(100), dec!(50));
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.50), dec!(100));
+
+        client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_over_max_size_should_fail() {
+        let client = client_with_caps(dec!(100), dec!(1000));
+        let request = LimitOrderRequest::new(U256::from(1), Side::Sell, dec!(0.10), dec!(100.01));
+
+        let err = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Order size 100.01 exceeds the maximum order size 100"
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_over_max_notional_should_fail() {
+        let client = client_with_caps(dec!(1000), dec!(50));
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.51), dec!(100.00));
+
+        let err = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Order value 51.0000 exceeds the maximum order value 50"
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_should_enforce_max_position() {
+        let client = client_from(config().with_max_position(dec!(100)).unwrap()).unwrap();
+        client.positions.insert(U256::from(1), dec!(60));
+        let sign = |side, size| {
+            let request = LimitOrderRequest::new(U256::from(1), side, dec!(0.50), size);
+            let client = &client;
+            async move {
+                client
+                    .sign_limit_order(&request, LimitOrderOverrides::default())
+                    .await
+            }
+        };
+
+        sign(Side::Buy, dec!(40)).await.unwrap();
+        sign(Side::Sell, dec!(60)).await.unwrap();
+        let err = sign(Side::Buy, dec!(0.01)).await.unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Order would take the position in token 1 to 100.01, above the maximum position 100"
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_should_count_signed_buys_against_max_position() {
+        let client = client_from(config().with_max_position(dec!(100)).unwrap()).unwrap();
+        client.positions.insert(U256::from(1), Decimal::ZERO);
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.50), dec!(90));
+
+        client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap();
+        client.validate_order(&request, LimitOrderOverrides::default()).unwrap_err();
+        let err = client
+            .sign_limit_order_blocking(&request, LimitOrderOverrides::default())
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Order would take the position in token 1 to 180, above the maximum position 100"
+        );
+        assert_eq!(*client.positions.get(&U256::from(1)).unwrap(), dec!(90));
+    }
+
+    #[test]
+    fn sign_limit_order_blocking_without_cached_position_should_fail() {
+        let client = client_from(config().with_max_position(dec!(100)).unwrap()).unwrap();
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.50), dec!(10));
+
+        let err = client
+            .sign_limit_order_blocking(&request, LimitOrderOverrides::default())
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "No position cached for token 1 to check against the maximum position"
+        );
+    }
+
+    #[test]
+    fn config_should_default_to_no_order_caps() {
+        let client = client();
+
+        assert_eq!(client.max_order_size, None);
+        assert_eq!(
+            client.max_order_notional, None,
+            "notional cap should be unset"
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_should_use_pinned_neg_risk() {
+        let mut client = client();
+        client.set_neg_risk(U256::from(1), true);
+        let pinned = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.45), dec!(10));
+        let unpinned = LimitOrderRequest::new(U256::from(2), Side::Buy, dec!(0.45), dec!(10));
+
+        let signed = client
+            .sign_limit_order(&pinned, LimitOrderOverrides::default())
+            .await
+            .unwrap();
+        let recovered = signed
+            .signature
+            .recover_address_from_prehash(&client.order_signing_hash(&signed.order, true))
+            .unwrap();
+        assert_eq!(recovered, client.address());
+
+        let signed = client
+            .sign_limit_order(&unpinned, LimitOrderOverrides::default())
+            .await
+            .unwrap();
+        let recovered = signed
+            .signature
+            .recover_address_from_prehash(&client.order_signing_hash(&signed.order, false))
+            .unwrap();
+        assert_eq!(recovered, client.address());
+
+        let overridden = client
+            .prepare_order(&pinned, LimitOrderOverrides::default().with_neg_risk(false))
+            .unwrap();
+        assert!(!overridden.neg_risk, "per-order override should win");
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_should_use_default_fee_rate() {
+        let mut client = client();
+        client.set_default_fee_rate_bps(25);
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.45), dec!(10));
+
+        let signed = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap();
+        let overridden = client
+            .sign_limit_order(
+                &request,
+                LimitOrderOverrides::default().with_fee_rate_bps(10),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(signed.order.feeRateBps, U256::from(25));
+        assert_eq!(overridden.order.feeRateBps, U256::from(10));
+    }
+
+    #[tokio::test]
+    async fn signature_type_override_should_change_signed_type() {
+        let client = client();
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.45), dec!(10));
+
+        let eoa = client
+            .sign_limit_order(
+                &request,
+                LimitOrderOverrides::default().with_signature_type(SignatureType::Eoa),
+            )
+            .await
+            .unwrap();
+        assert_eq!(eoa.order.signatureType, SignatureType::Eoa as u8);
+        assert_eq!(eoa.order.maker, client.address());
+        let recovered = eoa
+            .signature
+            .recover_address_from_prehash(&eoa.order.eip712_signing_hash(&client.domain))
+            .unwrap();
+        assert_eq!(recovered, client.address());
+
+        let proxy = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap();
+        assert_eq!(proxy.order.signatureType, SignatureType::Proxy as u8);
+        assert_eq!(proxy.order.maker, client.funder());
+    }
+
+    #[tokio::test]
+    async fn signature_type_override_should_validate_funder() {
+        let client = client();
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.45), dec!(10));
+
+        let err = client
+            .sign_limit_order(
+                &request,
+                LimitOrderOverrides::default().with_signature_type(SignatureType::GnosisSafe),
+            )
+            .await
+            .unwrap_err();
+        let validation = err.downcast_ref::<Validation>().unwrap();
+
+        assert!(
+            validation.reason.starts_with("GnosisSafe funder"),
+            "unexpected reason: {}",
+            validation.reason
+        );
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn rejected_order_should_log_structured_fields() {
+        use std::sync::Mutex;
+
+        use tracing_subscriber::layer::SubscriberExt as _;
+
+        struct CaptureWriter(Arc<Mutex<Vec<u8>>>);
+
+        impl std::io::Write for CaptureWriter {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().expect("lock").extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let writer = Arc::clone(&output);
+        let layer = tracing_subscriber::fmt::layer()
+            .with_writer(move || CaptureWriter(Arc::clone(&writer)))
+            .with_ansi(false);
+        let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(layer));
+
+        let client = client();
+        let request = LimitOrderRequest::new(U256::from(7), Side::Sell, dec!(0.995), dec!(10));
+        client.post_limit_order(&request).await.unwrap_err();
+
+        let output = String::from_utf8(output.lock().expect("lock").clone()).unwrap();
+        for field in [
+            "order rejected",
+            "token_id=7",
+            "side=SELL",
+            "price=0.995",
+            "size=10",
+            "reason=Unable to build Order: Price 0.995 has 3 decimal places",
+        ] {
+            assert!(output.contains(field), "missing {field} in: {output}");
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn rejection_reason_should_unwrap_error_body() {
+        let status = |message: &str| {
+            Error::status(
+                StatusCode::BAD_REQUEST,
+                Method::POST,
+                "/order".to_owned(),
+                message,
+            )
+        };
+
+        assert_eq!(
+            rejection_reason(&status(r#"{"error":"not enough balance / allowance"}"#)),
+            "not enough balance / allowance"
+        );
+        assert_eq!(rejection_reason(&status("bad gateway")), "bad gateway");
+        assert_eq!(
+            rejection_reason(&Error::validation("Invalid side: UNKNOWN")),
+            "Invalid side: UNKNOWN"
+        );
+    }
+
+    fn gtd_request(expires_in_secs: i64) -> LimitOrderRequest {
+        let mut request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.45), dec!(10));
+        request.order_type = Some(OrderType::GTD);
+        request.expiration =
+            DateTime::<Utc>::from_timestamp(Utc::now().timestamp() + expires_in_secs, 0);
+        request
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_gtd_outside_buffer_should_keep_expiration() {
+        let client = client();
+        let request = gtd_request(GTD_EXPIRATION_BUFFER_SECS + 30);
+
+        let signed = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap();
+
+        let expected = request.expiration.unwrap().timestamp().to_u64().unwrap();
+        assert_eq!(signed.order.expiration, U256::from(expected));
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_gtd_inside_buffer_should_fail() {
+        let client = client();
+        let request = gtd_request(GTD_EXPIRATION_BUFFER_SECS - 30);
+
+        let err = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap_err();
+
+        assert!(
+            err.downcast_ref::<Validation>()
+                .unwrap()
+                .reason
+                .ends_with("must be more than 60 seconds in the future"),
+            "expiration inside the buffer should be rejected"
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_gtd_inside_buffer_should_bump_when_configured() {
+        let client = bump_forward_client();
+        let request = gtd_request(GTD_EXPIRATION_BUFFER_SECS - 30);
+        let before = Utc::now().timestamp();
+
+        let signed = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap();
+
+        let expiration = signed.order.expiration.to::<i64>();
+        assert!(
+            expiration > before + GTD_EXPIRATION_BUFFER_SECS,
+            "expiration should be moved past the buffer"
+        );
+        assert!(
+            expiration <= Utc::now().timestamp() + GTD_EXPIRATION_BUFFER_SECS + 1,
+            "expiration should be moved to the earliest accepted time"
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_gtd_without_expiration_should_fail_before_bumping() {
+        let client = bump_forward_client();
+        let mut request = gtd_request(0);
+        request.expiration = None;
+
+        let err = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "GTD orders require an expiration"
+        );
+    }
+
+    fn bump_forward_client() -> HotPathClient {
+        let policies = HotPathPolicies::builder()
+            .fixed_tick_size(TickSize::Hundredth)
+            .fixed_neg_risk(false)
+            .fixed_fee_rate_bps(0)
+            .gtd_expiration(GtdExpiration::BumpForward)
+            .build()
+            .unwrap();
+
+        client_with_policies(SignatureType::Proxy, Address::repeat_byte(0x11), policies).unwrap()
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_expires_in_should_use_server_clock() {
+        let mut client = client();
+        client.policies.time = TimePolicy::FetchAndCache;
+        client.clock_offset.store(3_600, Ordering::Relaxed);
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.45), dec!(10))
+            .with_expires_in(Duration::from_secs(120));
+        let before = Utc::now().timestamp();
+
+        let signed = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap();
+
+        let expiration = signed.order.expiration.to::<i64>();
+        assert!(
+            (before + 3_720..=Utc::now().timestamp() + 3_720).contains(&expiration),
+            "expiration {expiration} should be 120s past server time"
+        );
+
+        client.policies.time = TimePolicy::Fixed;
+        let signed = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap();
+
+        let expiration = signed.order.expiration.to::<i64>();
+        assert!(
+            expiration <= Utc::now().timestamp() + 120,
+            "expiration {expiration} should be 120s past local time without FetchAndCache"
+        );
+    }
+
+    #[derive(Debug)]
+    struct FixedSalt;
+
+    impl SaltSource for FixedSalt {
+        fn next_salt(&self) -> u64 {
+            42
+        }
+    }
+
+    #[test]
+    fn debug_order_payload_should_match_fixture() {
+        let client = client();
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.45), dec!(10));
+
+        let debug = client
+            .debug_order_payload(&request, LimitOrderOverrides::default(), 42)
+            .unwrap();
+
+        assert_eq!(debug.salt, U256::from(42));
+        assert_eq!(debug.maker_amount, U256::from(4_500_000_u64));
+        assert_eq!(debug.taker_amount, U256::from(10_000_000_u64));
+        assert_eq!(debug.domain_name, "Polymarket CTF Exchange");
+        assert_eq!(debug.domain_version, "1");
+        assert_eq!(debug.chain_id, POLYGON);
+        assert_eq!(
+            debug.verifying_contract,
+            address!("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E")
+        );
+        assert_eq!(
+            debug.signing_hash,
+            b256!("0x0d5b6a84b3e6eec1470aadf481414005f30471dd75bd1921bfa8fc0f29147a4e")
+        );
+
+        let signature = client.signer.sign_hash_sync(&debug.signing_hash).unwrap();
+        let body: serde_json::Value = serde_json::from_str(&debug.body).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "order": {
+                    "salt": 42,
+                    "maker": Address::repeat_byte(0x11),
+                    "signer": address!("0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"),
+                    "taker": Address::ZERO,
+                    "tokenId": "1",
+                    "makerAmount": "4500000",
+                    "takerAmount": "10000000",
+                    "expiration": "0",
+                    "nonce": "0",
+                    "feeRateBps": "0",
+                    "side": "BUY",
+                    "signatureType": 1,
+                    "signature": signature.to_string(),
+                },
+                "orderType": "GTC",
+                "owner": Uuid::nil(),
+                "postOnly": false,
+            })
+        );
+    }
+
+    #[test]
+    fn debug_order_payload_should_skip_network_backed_caps() {
+        let config = config()
+            .with_max_position(dec!(100))
+            .unwrap()
+            .with_market_order_size_limits(true);
+        let client = client_from(config).unwrap();
+        let request = LimitOrderRequest::new(U256::from(1), Side::Buy, dec!(0.45), dec!(10));
+
+        let first = client
+            .debug_order_payload(&request, LimitOrderOverrides::default(), 42)
+            .unwrap();
+        let second = client
+            .debug_order_payload(&request, LimitOrderOverrides::default(), 42)
+            .unwrap();
+
+        assert_eq!(first, second);
+        assert!(client.positions.is_empty(), "nothing should be fetched or reserved");
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_blocking_should_match_async() {
+        let client = client().with_salt_source(FixedSalt);
+        let request = LimitOrderRequest::new(U256::from(1), Side::Sell, dec!(0.45), dec!(10));
+
+        let signed = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await
+            .unwrap();
+        let blocking = client
+            .sign_limit_order_blocking(&request, LimitOrderOverrides::default())
+            .unwrap();
+
+        assert_eq!(blocking, signed);
+    }
+
+    #[test]
+    fn exchange_for_should_route_neg_risk_separately() {
+        let client = client();
+
+        let exchange = client.exchange_for(false).unwrap();
+        let neg_risk_exchange = client.exchange_for(true).unwrap();
+
+        assert_ne!(exchange, neg_risk_exchange);
+        assert_eq!(
+            Some(exchange),
+            contract_config(POLYGON, false).map(|config| config.exchange)
+        );
+        assert_eq!(
+            Some(neg_risk_exchange),
+            contract_config(POLYGON, true).map(|config| config.exchange)
+        );
+    }
+
+    #[test]
+    fn neg_risk_domain_should_verify_against_exchange_not_adapter() {
+        let client = client();
+        let neg_risk_config = contract_config(POLYGON, true).unwrap();
+        let adapter = neg_risk_config.neg_risk_adapter.unwrap();
+
+        assert_eq!(
+            client.neg_risk_domain.verifying_contract,
+            Some(neg_risk_config.exchange)
+        );
+        assert_ne!(client.neg_risk_domain.verifying_contract, Some(adapter));
+        assert_eq!(
+            client.domain.verifying_contract,
+            Some(contract_config(POLYGON, false).unwrap().exchange)
+        );
+    }
+
+    #[test]
+    fn domain_separator_should_match_polygon_exchanges() {
+        let client = client();
+
+        assert_eq!(
+            client.domain_separator(false),
+            b256!("1a573e3617c78403b5b4b892827992f027b03d4eaf570048b8ee8cdd84d151be")
+        );
+        assert_eq!(
+            client.domain_separator(true),
+            b256!("82cb6aa85babb812f4b521a12b10f0cbc68d2b44be7bc02c047004f544adb49f")
+        );
+    }
+
+    #[test]
+    fn http_host_should_be_rejected_by_default() {
+        let mut config = config();
+        config.host = Url::parse("http://localhost:8080").unwrap();
+
+        let err = client_from(config).unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "hotpath host http://localhost:8080/ must use https; set allow_insecure for local testing"
+        );
+    }
+
+    #[test]
+    fn http_data_host_should_be_rejected_by_default() {
+        let config = config().with_data_host(Url::parse("http://localhost:8080").unwrap());
+
+        client_from(config).unwrap_err();
+    }
+
+    #[test]
+    fn http_host_should_be_accepted_with_allow_insecure() {
+        let mut config = config().with_allow_insecure(true);
+        config.host = Url::parse("http://localhost:8080").unwrap();
+
+        client_from(config).unwrap();
+    }
+
+    #[cfg(feature = "ws")]
+    #[test]
+    fn live_bbo_should_follow_market_events() {
+        let client = client();
+        let event = |value: serde_json::Value| {
+            serde_json::from_value::<WsMessage>(value).expect("event should deserialize")
+        };
+        let market = "0x0000000000000000000000000000000000000000000000000000000000000001";
+
+        assert_eq!(client.live_bbo(U256::from(1)), None);
+
+        client.record_market_event(&event(serde_json::json!({
+            "event_type": "book",
+            "asset_id": "1",
+            "market": market,
+            "timestamp": "1700000000000",
+            "bids": [{"price": "0.44", "size": "100"}, {"price": "0.45", "size": "10"}],
+            "asks": [{"price": "0.48", "size": "50"}, {"price": "0.47", "size": "5"}]
+        })));
+        let bbo = client
+            .live_bbo(U256::from(1))
+            .expect("book should populate the cache");
+        assert_eq!(bbo.best_bid, Some(dec!(0.45)));
+        assert_eq!(bbo.best_ask, Some(dec!(0.47)));
+
+        client.record_market_event(&event(serde_json::json!({
+            "event_type": "price_change",
+            "market": market,
+            "timestamp": "1700000000001",
+            "price_changes": [{
+                "asset_id": "1",
+                "price": "0.46",
+                "size": "20",
+                "side": "BUY",
+                "best_bid": "0.46",
+                "best_ask": "0.47"
+            }]
+        })));
+        let bbo = client
+            .live_bbo(U256::from(1))
+            .expect("cache should keep the token");
+        assert_eq!(bbo.best_bid, Some(dec!(0.46)));
+        assert_eq!(bbo.best_ask, Some(dec!(0.47)));
+
+        client.record_market_event(&event(serde_json::json!({
+            "event_type": "best_bid_ask",
+            "market": market,
+            "asset_id": "1",
+            "best_bid": "0.43",
+            "best_ask": "0.49",
+            "spread": "0.06",
+            "timestamp": "1700000000002"
+        })));
+        let bbo = client
+            .live_bbo(U256::from(1))
+            .expect("cache should keep the token");
+        assert_eq!(bbo.best_bid, Some(dec!(0.43)));
+        assert_eq!(bbo.best_ask, Some(dec!(0.49)));
+
+        assert_eq!(
+            client.clone().live_bbo(U256::from(1)),
+            Some(bbo),
+            "clones should share the cache"
+        );
+        assert_eq!(client.live_bbo(U256::from(2)), None);
+    }
 }
@@ -1,15 +1,79 @@
+use std::collections::HashSet;
+use std::num::NonZeroUsize;
 use std::str::FromStr as _;
+use std::time::Duration;
 
+use reqwest::{Client as ReqwestClient, ClientBuilder};
 use secrecy::SecretString;
 use url::Url;
 
-use crate::POLYGON;
 use crate::Result;
 use crate::clob::types::SignatureType;
 use crate::error::Error;
-use crate::hotpath::policy::HotPathPolicies;
-use crate::hotpath::types::SignatureTypeInput;
-use crate::types::{Address, ChainId};
+use crate::hotpath::policy::{ClockTolerance, HotPathPolicies, RetryPolicy};
+use crate::hotpath::types::{CredentialMode, SignatureTypeInput};
+use crate::types::{Address, ChainId, Decimal, U256};
+use crate::{POLYGON, PRIVATE_KEY_VAR};
+
+/// Default number of order ids sent per cancel request by `HotPathClient::cancel_all`.
+const DEFAULT_CANCEL_CHUNK_SIZE: usize = 500;
+/// Default number of orders sent per `POST /orders` request, the exchange's batch limit.
+const DEFAULT_MAX_BATCH_SIZE: usize = 15;
+/// Default retries of a failed chunk in `HotPathClient::cancel_all`.
+const DEFAULT_CANCEL_RETRY: RetryPolicy =
+    RetryPolicy::new(2, Duration::from_millis(100), Duration::from_secs(1));
+/// Default interval between HTTP/2 keep-alive pings.
+const DEFAULT_HTTP2_KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(10);
+/// Default wait for an HTTP/2 keep-alive acknowledgement.
+const DEFAULT_HTTP2_KEEP_ALIVE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Environment variable read by [`HotPathConfig::from_env`] for the CLOB host.
+pub const HOST_VAR: &str = "CLOB_HOST";
+/// Environment variable read by [`HotPathConfig::from_env`] for the signature type.
+pub const SIGNATURE_TYPE_VAR: &str = "CLOB_SIGNATURE_TYPE";
+/// Environment variable read by [`HotPathConfig::from_env`] for the funder address.
+pub const FUNDER_VAR: &str = "CLOB_FUNDER";
+
+/// HTTP/2 connection tuning for the client's connection to the exchange.
+///
+/// The defaults keep one connection warm between bursts with frequent keep-alive pings and
+/// let flow-control windows grow with throughput, so bursts don't stall on a cold or
+/// throttled connection.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Http2Settings {
+    /// Interval between keep-alive pings, sent even while idle. `None` disables them.
+    pub keep_alive_interval: Option<Duration>,
+    /// How long to wait for a ping acknowledgement before dropping the connection.
+    pub keep_alive_timeout: Duration,
+    /// Size flow-control windows from the measured bandwidth-delay product.
+    pub adaptive_window: bool,
+}
+
+impl Http2Settings {
+    #[must_use]
+    pub const fn new(
+        keep_alive_interval: Option<Duration>,
+        keep_alive_timeout: Duration,
+        adaptive_window: bool,
+    ) -> Self {
+        Self {
+            keep_alive_interval,
+            keep_alive_timeout,
+            adaptive_window,
+        }
+    }
+}
+
+impl Default for Http2Settings {
+    fn default() -> Self {
+        Self::new(
+            Some(DEFAULT_HTTP2_KEEP_ALIVE_INTERVAL),
+            DEFAULT_HTTP2_KEEP_ALIVE_TIMEOUT,
+            true,
+        )
+    }
+}
 
 /// Raw signing values typically passed from app-level bot config.
 #[derive(Clone, Debug)]
@@ -21,17 +85,112 @@ pub struct RawHotPathSigningConfig {
 
 /// Hot-path bootstrap configuration.
 #[derive(Clone, Debug)]
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "Each flag is an independent opt-in toggled by its own `with_*` setter"
+)]
 pub struct HotPathConfig {
     pub host: Url,
+    /// Optional read-only host for book, price and market endpoints. Orders, auth and clock
+    /// sync always use `host`.
+    pub data_host: Option<Url>,
     pub chain_id: ChainId,
     pub private_key: SecretString,
     pub signature_type: SignatureType,
     pub funder: Address,
     pub nonce: Option<u32>,
+    /// Whether bootstrap and credential refresh create a key, derive one, or try both.
+    pub credential_mode: CredentialMode,
+    /// JSON-RPC endpoint `HotPathClient::bootstrap` uses to check that the funder is a
+    /// deployed contract, as Proxy and `GnosisSafe` funders must be. `None` skips the check,
+    /// as do the synchronous `with_credentials` constructors.
+    pub funder_rpc_url: Option<Url>,
     pub policies: HotPathPolicies,
+    /// Accepted clock skew for L1 and L2 header timestamps.
+    pub clock_tolerance: ClockTolerance,
+    /// Reuse L2 headers for identical requests signed within the same second.
+    pub l2_header_cache: bool,
+    /// Token ids the client may sign orders for. Empty means no restriction.
+    pub allowed_token_ids: HashSet<U256>,
+    /// Largest order size, in shares, the client will sign. `None` means no cap.
+    ///
+    /// The order caps here, [`HotPathPolicies::min_order_value`] and the market's published
+    /// bounds are all checked together before signing; see
+    /// [`HotPathClient::sign_limit_order`](crate::hotpath::HotPathClient::sign_limit_order).
+    pub max_order_size: Option<Decimal>,
+    /// Largest order notional (`price * size`) the client will sign. `None` means no cap.
+    pub max_order_notional: Option<Decimal>,
+    /// Largest net position, in shares per token, a BUY order may take the account to.
+    /// `None` means no cap; see
+    /// [`HotPathClient::sign_limit_order`](crate::hotpath::HotPathClient::sign_limit_order).
+    pub max_position: Option<Decimal>,
+    /// Enforce the order size bounds each market publishes when signing; see
+    /// [`HotPathClient::order_size_limits`](crate::hotpath::HotPathClient::order_size_limits).
+    pub market_order_size_limits: bool,
+    /// Permit `http://` hosts, which send credentials in cleartext. Only for local testing.
+    pub allow_insecure: bool,
+    /// Order ids sent per `DELETE /orders` request when cancelling all open orders.
+    pub cancel_chunk_size: usize,
+    /// Orders sent per `POST /orders` request; larger batches are split across requests.
+    pub max_batch_size: usize,
+    /// Keep a local map of the resting orders this client places; see
+    /// [`HotPathClient::tracked_orders`](crate::hotpath::HotPathClient::tracked_orders).
+    pub track_orders: bool,
+    /// Sign orders without an explicit nonce with the nonce `/auth/derive-api-key` reported,
+    /// instead of `0`; see
+    /// [`HotPathClient::derived_nonce`](crate::hotpath::HotPathClient::derived_nonce).
+    pub use_derived_nonce: bool,
+    /// Retries for idempotent market-data reads such as books, prices and midpoints.
+    pub read_retry: RetryPolicy,
+    /// Retries for
+    /// [`HotPathClient::post_limit_order_reliable`](crate::hotpath::HotPathClient::post_limit_order_reliable),
+    /// off by default; other order posts are never retried. Each retry resends the identical
+    /// signed order, so the exchange recognises it as the same order rather than a new one.
+    pub write_retry: RetryPolicy,
+    /// Retries for each chunk of
+    /// [`HotPathClient::cancel_all`](crate::hotpath::HotPathClient::cancel_all); cancels are
+    /// idempotent, so by default a chunk is retried twice with backoff.
+    pub cancel_retry: RetryPolicy,
+    /// Most order submissions the client has in flight at once, counting each `POST /order`
+    /// and each `POST /orders` request of a batch as one. Further submissions wait, in
+    /// arrival order, for an earlier one to finish. `None` means no cap.
+    pub max_in_flight_orders: Option<NonZeroUsize>,
+    /// How long a submission waits for a slot under [`Self::max_in_flight_orders`] before
+    /// failing with a timeout error. `None` waits indefinitely.
+    pub order_permit_timeout: Option<Duration>,
+    /// HTTP/2 tuning of the client the `bootstrap` and `with_credentials` constructors
+    /// build. Clients passed to the `_with_client` constructors are used as they are.
+    pub http2: Http2Settings,
 }
 
 impl HotPathConfig {
+    /// Builds a Polygon config from [`HOST_VAR`], [`PRIVATE_KEY_VAR`], [`SIGNATURE_TYPE_VAR`]
+    /// and [`FUNDER_VAR`]. Pair with
+    /// [`Credentials::from_env`](crate::auth::Credentials::from_env) to configure a client
+    /// purely through the environment.
+    ///
+    /// The error names every variable that is missing or empty.
+    pub fn from_env(policies: HotPathPolicies) -> Result<Self> {
+        Self::from_lookup(|name| std::env::var(name).ok(), policies)
+    }
+
+    pub(crate) fn from_lookup<F: Fn(&str) -> Option<String>>(
+        lookup: F,
+        policies: HotPathPolicies,
+    ) -> Result<Self> {
+        let [host, private_key, signature_type, funder] = crate::required_vars(
+            [HOST_VAR, PRIVATE_KEY_VAR, SIGNATURE_TYPE_VAR, FUNDER_VAR],
+            lookup,
+        )?;
+        let raw = RawHotPathSigningConfig {
+            private_key: SecretString::from(private_key),
+            signature_type,
+            funder,
+        };
+
+        Self::from_raw(&host, POLYGON, raw, policies)
+    }
+
     pub fn from_raw(
         host: &str,
         chain_id: ChainId,
@@ -84,12 +243,435 @@ impl HotPathConfig {
 
         Ok(Self {
             host,
+            data_host: None,
             chain_id,
             private_key,
             signature_type,
             funder,
             nonce,
+            credential_mode: CredentialMode::default(),
+            funder_rpc_url: None,
             policies,
+            clock_tolerance: ClockTolerance::default(),
+            l2_header_cache: false,
+            allowed_token_ids: HashSet::new(),
+            max_order_size: None,
+            max_order_notional: None,
+            max_position: None,
+            market_order_size_limits: false,
+            allow_insecure: false,
+            cancel_chunk_size: DEFAULT_CANCEL_CHUNK_SIZE,
+            max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+            track_orders: false,
+            use_derived_nonce: false,
+            read_retry: RetryPolicy::NONE,
+            write_retry: RetryPolicy::NONE,
+            cancel_retry: DEFAULT_CANCEL_RETRY,
+            max_in_flight_orders: None,
+            order_permit_timeout: None,
+            http2: Http2Settings::default(),
         })
     }
+
+    #[must_use]
+    pub const fn with_clock_tolerance(mut self, clock_tolerance: ClockTolerance) -> Self {
+        self.clock_tolerance = clock_tolerance;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_credential_mode(mut self, credential_mode: CredentialMode) -> Self {
+        self.credential_mode = credential_mode;
+        self
+    }
+
+    #[must_use]
+    pub fn with_funder_rpc_url(mut self, rpc_url: Url) -> Self {
+        self.funder_rpc_url = Some(rpc_url);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_l2_header_cache(mut self, enabled: bool) -> Self {
+        self.l2_header_cache = enabled;
+        self
+    }
+
+    #[must_use]
+    pub fn with_allowed_token_ids<I: IntoIterator<Item = U256>>(mut self, token_ids: I) -> Self {
+        self.allowed_token_ids = token_ids.into_iter().collect();
+        self
+    }
+
+    /// Caps the order size, in shares; rejects a cap that isn't positive.
+    pub fn with_max_order_size(mut self, max_order_size: Decimal) -> Result<Self> {
+        self.max_order_size = Some(positive_limit("max_order_size", max_order_size)?);
+        Ok(self)
+    }
+
+    /// Caps the order notional (`price * size`); rejects a cap that isn't positive.
+    pub fn with_max_order_notional(mut self, max_order_notional: Decimal) -> Result<Self> {
+        self.max_order_notional = Some(positive_limit("max_order_notional", max_order_notional)?);
+        Ok(self)
+    }
+
+    /// Caps the net position per token a BUY may reach; rejects a cap that isn't positive.
+    pub fn with_max_position(mut self, max_position: Decimal) -> Result<Self> {
+        self.max_position = Some(positive_limit("max_position", max_position)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    pub const fn with_market_order_size_limits(mut self, enabled: bool) -> Self {
+        self.market_order_size_limits = enabled;
+        self
+    }
+
+    #[must_use]
+    pub fn with_data_host(mut self, data_host: Url) -> Self {
+        self.data_host = Some(data_host);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_allow_insecure(mut self, allow_insecure: bool) -> Self {
+        self.allow_insecure = allow_insecure;
+        self
+    }
+
+    /// Sets the order ids sent per cancel request; zero is treated as one.
+    #[must_use]
+    pub const fn with_cancel_chunk_size(mut self, cancel_chunk_size: usize) -> Self {
+        self.cancel_chunk_size = cancel_chunk_size;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_order_tracking(mut self, enabled: bool) -> Self {
+        self.track_orders = enabled;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_derived_nonce(mut self, enabled: bool) -> Self {
+        self.use_derived_nonce = enabled;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_read_retry(mut self, read_retry: RetryPolicy) -> Self {
+        self.read_retry = read_retry;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_write_retry(mut self, write_retry: RetryPolicy) -> Self {
+        self.write_retry = write_retry;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_cancel_retry(mut self, cancel_retry: RetryPolicy) -> Self {
+        self.cancel_retry = cancel_retry;
+        self
+    }
+
+    /// Caps concurrent limit order submissions.
+    #[must_use]
+    pub const fn with_max_in_flight_orders(mut self, max_in_flight_orders: NonZeroUsize) -> Self {
+        self.max_in_flight_orders = Some(max_in_flight_orders);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_order_permit_timeout(mut self, order_permit_timeout: Duration) -> Self {
+        self.order_permit_timeout = Some(order_permit_timeout);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_http2(mut self, http2: Http2Settings) -> Self {
+        self.http2 = http2;
+        self
+    }
+
+    /// Sets the orders sent per batch request; zero is treated as one.
+    #[must_use]
+    pub const fn with_max_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.max_batch_size = max_batch_size;
+        self
+    }
+
+    /// Builds the HTTP client used by constructors that aren't given one, applying
+    /// [`Self::http2`].
+    pub(crate) fn http_client(&self) -> Result<ReqwestClient> {
+        Ok(self.http_client_builder().build()?)
+    }
+
+    /// Returns a client builder with [`Self::http2`] applied.
+    pub(crate) fn http_client_builder(&self) -> ClientBuilder {
+        let builder = ReqwestClient::builder().http2_adaptive_window(self.http2.adaptive_window);
+        match self.http2.keep_alive_interval {
+            Some(interval) => builder
+                .http2_keep_alive_interval(interval)
+                .http2_keep_alive_timeout(self.http2.keep_alive_timeout)
+                .http2_keep_alive_while_idle(true),
+            None => builder,
+        }
+    }
+
+    /// Rejects order caps that aren't positive, including ones set on the fields directly.
+    ///
+    /// Runs when a client is built from this config, after all builder options are applied.
+    pub(crate) fn validate_order_limits(&self) -> Result<()> {
+        for (name, limit) in [
+            ("max_order_size", self.max_order_size),
+            ("max_order_notional", self.max_order_notional),
+            ("max_position", self.max_position),
+        ] {
+            if let Some(limit) = limit {
+                positive_limit(name, limit)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Rejects non-`https` hosts unless [`Self::allow_insecure`] is set.
+    ///
+    /// Runs when a client is built from this config, after all builder options are applied.
+    pub(crate) fn validate_hosts(&self) -> Result<()> {
+        if self.allow_insecure {
+            return Ok(());
+        }
+
+        for host in std::iter::once(&self.host).chain(self.data_host.as_ref()) {
+            if host.scheme() != "https" {
+                return Err(Error::validation(format!(
+                    "hotpath host {host} must use https; set allow_insecure for local testing"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn positive_limit(name: &str, limit: Decimal) -> Result<Decimal> {
+    if limit.is_zero() || limit.is_sign_negative() {
+        return Err(Error::validation(format!(
+            "{name} must be positive, got {limit}"
+        )));
+    }
+    Ok(limit)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read as _, Write as _};
+    use std::net::{TcpListener, TcpStream};
+    use std::thread;
+    use std::time::Instant;
+
+    use super::*;
+    use crate::clob::types::TickSize;
+    use crate::tests::env;
+
+    fn policies() -> HotPathPolicies {
+        HotPathPolicies::fixed(TickSize::Hundredth, false, 0)
+    }
+
+    #[test]
+    fn from_env_should_build_config() -> Result<()> {
+        let config = HotPathConfig::from_lookup(
+            env(&[
+                (HOST_VAR, "https://clob.polymarket.com"),
+                (
+                    PRIVATE_KEY_VAR,
+                    "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+                ),
+                (SIGNATURE_TYPE_VAR, "proxy"),
+                (FUNDER_VAR, "0x1111111111111111111111111111111111111111"),
+            ]),
+            policies(),
+        )?;
+
+        assert_eq!(config.host.as_str(), "https://clob.polymarket.com/");
+        assert_eq!(config.chain_id, POLYGON);
+        assert_eq!(config.signature_type, SignatureType::Proxy);
+        assert_eq!(config.funder, Address::repeat_byte(0x11));
+
+        Ok(())
+    }
+
+    /// A frame sent by the client: type, flags, stream id and payload.
+    type Frame = (u8, u8, u32, Vec<u8>);
+
+    const HEADERS: u8 = 0x1;
+    const SETTINGS: u8 = 0x4;
+    const PING: u8 = 0x6;
+    const WINDOW_UPDATE: u8 = 0x8;
+    const ACK: u8 = 0x1;
+    const SETTINGS_INITIAL_WINDOW_SIZE: u16 = 0x4;
+
+    fn write_frame(stream: &mut TcpStream, kind: u8, flags: u8, id: u32, payload: &[u8]) {
+        let len = u32::try_from(payload.len()).unwrap().to_be_bytes();
+        let mut frame = vec![len[1], len[2], len[3], kind, flags];
+        frame.extend_from_slice(&id.to_be_bytes());
+        frame.extend_from_slice(payload);
+        stream.write_all(&frame).unwrap();
+    }
+
+    /// Sends one GET over cleartext HTTP/2 from a client built by
+    /// [`HotPathConfig::http_client_builder`] and returns every frame it writes until
+    /// `linger` passes. The peer acknowledges SETTINGS and PINGs and answers the request
+    /// with an empty `200`.
+    async fn record_h2c_frames(http2: Http2Settings, linger: Duration) -> Result<Vec<Frame>> {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+
+        let peer = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let deadline = Instant::now() + linger;
+            let mut preface = [0; 24];
+            stream.read_exact(&mut preface).unwrap();
+            write_frame(&mut stream, SETTINGS, 0, 0, &[]);
+
+            let mut frames = Vec::new();
+            let mut header = [0; 9];
+            loop {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    break;
+                }
+                stream.set_read_timeout(Some(remaining)).unwrap();
+                if stream.read_exact(&mut header).is_err() {
+                    break;
+                }
+
+                let len = u32::from_be_bytes([0, header[0], header[1], header[2]]);
+                let (kind, flags) = (header[3], header[4]);
+                let id =
+                    u32::from_be_bytes([header[5], header[6], header[7], header[8]]) & 0x7fff_ffff;
+                let mut payload = vec![0; usize::try_from(len).unwrap()];
+                stream.read_exact(&mut payload).unwrap();
+
+                match kind {
+                    SETTINGS if flags & ACK == 0 => {
+                        write_frame(&mut stream, SETTINGS, ACK, 0, &[]);
+                    }
+                    PING if flags & ACK == 0 => write_frame(&mut stream, PING, ACK, 0, &payload),
+                    // `:status: 200` from the static table; END_STREAM | END_HEADERS.
+                    HEADERS => write_frame(&mut stream, HEADERS, 0x5, id, &[0x88]),
+                    _ => {}
+                }
+                frames.push((kind, flags, id, payload));
+            }
+            frames
+        });
+
+        let config = config()?.with_http2(http2);
+        let client = config
+            .http_client_builder()
+            .http2_prior_knowledge()
+            .build()?;
+        let response = client.get(url).send().await?;
+        assert_eq!(
+            response.status(),
+            reqwest::StatusCode::OK,
+            "peer should answer the request"
+        );
+
+        // Keep the connection open while the peer records what the client sends when idle.
+        tokio::time::sleep(linger).await;
+        let frames = peer.join().unwrap();
+        drop(client);
+
+        Ok(frames)
+    }
+
+    fn initial_window_size(frames: &[Frame]) -> Option<u32> {
+        frames
+            .iter()
+            .filter(|(kind, flags, ..)| *kind == SETTINGS && flags & ACK == 0)
+            .flat_map(|(.., payload)| payload.chunks_exact(6))
+            .filter(|setting| {
+                u16::from_be_bytes([setting[0], setting[1]]) == SETTINGS_INITIAL_WINDOW_SIZE
+            })
+            .map(|setting| u32::from_be_bytes([setting[2], setting[3], setting[4], setting[5]]))
+            .next_back()
+    }
+
+    fn pings(frames: &[Frame]) -> usize {
+        frames
+            .iter()
+            .filter(|(kind, flags, ..)| *kind == PING && flags & ACK == 0)
+            .count()
+    }
+
+    fn connection_window_updates(frames: &[Frame]) -> usize {
+        frames
+            .iter()
+            .filter(|(kind, _, id, _)| *kind == WINDOW_UPDATE && *id == 0)
+            .count()
+    }
+
+    fn config() -> Result<HotPathConfig> {
+        HotPathConfig::new(
+            Url::parse("https://clob.polymarket.com")?,
+            POLYGON,
+            SecretString::from(String::new()),
+            SignatureType::Proxy,
+            Address::repeat_byte(0x11),
+            None,
+            policies(),
+        )
+    }
+
+    #[test]
+    fn http2_settings_should_default_to_keep_alive_and_adaptive_window() -> Result<()> {
+        assert_eq!(
+            config()?.http2,
+            Http2Settings::new(Some(Duration::from_secs(10)), Duration::from_secs(5), true)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn http_client_builder_should_apply_http2_settings() -> Result<()> {
+        let linger = Duration::from_millis(300);
+
+        let warm = Http2Settings::new(
+            Some(Duration::from_millis(50)),
+            Duration::from_secs(1),
+            true,
+        );
+        let frames = record_h2c_frames(warm, linger).await?;
+        assert!(pings(&frames) > 0, "idle connection should be pinged");
+        // Adaptive windows start at the spec default and grow from measured throughput.
+        assert_eq!(initial_window_size(&frames), Some(65_535));
+        assert_eq!(connection_window_updates(&frames), 0);
+
+        let cold = Http2Settings::new(None, Duration::ZERO, false);
+        let frames = record_h2c_frames(cold, linger).await?;
+        assert_eq!(pings(&frames), 0);
+        assert_eq!(initial_window_size(&frames), Some(2 * 1024 * 1024));
+        assert_eq!(connection_window_updates(&frames), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_env_should_name_missing_variables() {
+        let err = HotPathConfig::from_lookup(
+            env(&[(HOST_VAR, "https://clob.polymarket.com")]),
+            policies(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Validation: invalid: missing environment variables: POLYMARKET_PRIVATE_KEY, \
+            CLOB_SIGNATURE_TYPE, CLOB_FUNDER"
+        );
+    }
 }
@@ -0,0 +1,52 @@
+use async_trait::async_trait;
+
+use crate::Result;
+use crate::clob::types::response::OrderBookSummaryResponse;
+use crate::hotpath::{Bbo, HotPathClient};
+use crate::types::{Decimal, U256};
+
+/// Market-data reads of a [`HotPathClient`].
+///
+/// Write strategies against this trait instead of the concrete client so they can be unit
+/// tested with [`MockMarketData`](crate::hotpath::MockMarketData), without a server.
+#[async_trait]
+pub trait ClobMarketData: Send + Sync {
+    /// See [`HotPathClient::get_order_book`].
+    async fn get_order_book(
+        &self,
+        token_id: U256,
+        depth: Option<usize>,
+    ) -> Result<Option<OrderBookSummaryResponse>>;
+
+    /// See [`HotPathClient::get_midpoint`].
+    async fn get_midpoint(&self, token_id: U256) -> Result<Option<Decimal>>;
+
+    /// See [`HotPathClient::get_bbo`].
+    async fn get_bbo(&self, token_id: U256) -> Result<Bbo>;
+
+    /// See [`HotPathClient::get_last_trade_price`].
+    async fn get_last_trade_price(&self, token_id: U256) -> Result<Option<Decimal>>;
+}
+
+#[async_trait]
+impl ClobMarketData for HotPathClient {
+    async fn get_order_book(
+        &self,
+        token_id: U256,
+        depth: Option<usize>,
+    ) -> Result<Option<OrderBookSummaryResponse>> {
+        HotPathClient::get_order_book(self, token_id, depth).await
+    }
+
+    async fn get_midpoint(&self, token_id: U256) -> Result<Option<Decimal>> {
+        HotPathClient::get_midpoint(self, token_id).await
+    }
+
+    async fn get_bbo(&self, token_id: U256) -> Result<Bbo> {
+        HotPathClient::get_bbo(self, token_id).await
+    }
+
+    async fn get_last_trade_price(&self, token_id: U256) -> Result<Option<Decimal>> {
+        HotPathClient::get_last_trade_price(self, token_id).await
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use async_trait::async_trait;
+
+use crate::Result;
+use crate::clob::types::OrderStatusType;
+use crate::clob::types::response::{
+    CancelOrdersResponse, OrderBookSummaryResponse, PostOrderResponse,
+};
+use crate::hotpath::types::truncate_book;
+use crate::hotpath::{Bbo, ClobMarketData, ClobOrders, LimitOrderOverrides, LimitOrderRequest};
+use crate::types::{Decimal, U256};
+
+/// A call recorded by [`MockClobClient`].
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub enum MockCall {
+    /// A limit order submission; batch submissions record one call per order.
+    PostLimitOrder {
+        request: LimitOrderRequest,
+        overrides: LimitOrderOverrides,
+    },
+    CancelOrder {
+        order_id: String,
+    },
+}
+
+/// In-memory [`ClobOrders`] that records every call and replays canned responses, for
+/// testing strategies without a server.
+///
+/// Responses queued with [`Self::push_post_response`] and [`Self::push_cancel_response`]
+/// are returned in order. Once a queue is empty, orders are accepted as `Live` with ids
+/// `mock-order-0`, `mock-order-1`, ... and cancels succeed for the requested id.
+#[derive(Debug, Default)]
+pub struct MockClobClient {
+    calls: Mutex<Vec<MockCall>>,
+    post_responses: Mutex<VecDeque<Result<PostOrderResponse>>>,
+    cancel_responses: Mutex<VecDeque<Result<CancelOrdersResponse>>>,
+}
+
+impl MockClobClient {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues the response for the next order submission.
+    pub fn push_post_response(&self, response: Result<PostOrderResponse>) {
+        lock(&self.post_responses).push_back(response);
+    }
+
+    /// Queues the response for the next cancel.
+    pub fn push_cancel_response(&self, response: Result<CancelOrdersResponse>) {
+        lock(&self.cancel_responses).push_back(response);
+    }
+
+    /// Returns every call made so far, in order.
+    #[must_use]
+    pub fn calls(&self) -> Vec<MockCall> {
+        lock(&self.calls).clone()
+    }
+
+    /// Returns the requests of every order submitted so far, in order.
+    #[must_use]
+    pub fn posted_orders(&self) -> Vec<LimitOrderRequest> {
+        lock(&self.calls)
+            .iter()
+            .filter_map(|call| match call {
+                MockCall::PostLimitOrder { request, .. } => Some(request.clone()),
+                MockCall::CancelOrder { .. } => None,
+            })
+            .collect()
+    }
+
+    fn record_post(
+        &self,
+        request: &LimitOrderRequest,
+        overrides: LimitOrderOverrides,
+    ) -> Result<PostOrderResponse> {
+        let mut calls = lock(&self.calls);
+        let order_number = calls
+            .iter()
+            .filter(|call| matches!(call, MockCall::PostLimitOrder { .. }))
+            .count();
+        calls.push(MockCall::PostLimitOrder {
+            request: request.clone(),
+            overrides,
+        });
+
+        lock(&self.post_responses).pop_front().unwrap_or_else(|| {
+            Ok(PostOrderResponse::builder()
+                .making_amount(Decimal::ZERO)
+                .taking_amount(Decimal::ZERO)
+                .order_id(format!("mock-order-{order_number}"))
+                .status(OrderStatusType::Live)
+                .success(true)
+                .build())
+        })
+    }
+}
+
+#[async_trait]
+impl ClobOrders for MockClobClient {
+    async fn post_limit_order(&self, request: &LimitOrderRequest) -> Result<PostOrderResponse> {
+        self.record_post(request, LimitOrderOverrides::default())
+    }
+
+    async fn post_limit_order_with_overrides(
+        &self,
+        request: &LimitOrderRequest,
+        overrides: LimitOrderOverrides,
+    ) -> Result<PostOrderResponse> {
+        self.record_post(request, overrides)
+    }
+
+    async fn post_limit_orders(
+        &self,
+        requests: &[LimitOrderRequest],
+    ) -> Result<Vec<Result<PostOrderResponse>>> {
+        Ok(requests
+            .iter()
+            .map(|request| self.record_post(request, LimitOrderOverrides::default()))
+            .collect())
+    }
+
+    async fn cancel_order(&self, order_id: &str) -> Result<CancelOrdersResponse> {
+        lock(&self.calls).push(MockCall::CancelOrder {
+            order_id: order_id.to_owned(),
+        });
+
+        lock(&self.cancel_responses).pop_front().unwrap_or_else(|| {
+            Ok(CancelOrdersResponse::builder()
+                .canceled(vec![order_id.to_owned()])
+                .build())
+        })
+    }
+}
+
+/// In-memory [`ClobMarketData`] serving injected order books, for testing strategies
+/// against deterministic book state without a server.
+///
+/// Midpoints and BBOs are derived from the injected books; a midpoint needs both sides.
+/// Tokens without a book behave like tokens without an order book on the exchange.
+#[derive(Debug, Default)]
+pub struct MockMarketData {
+    books: Mutex<HashMap<U256, OrderBookSummaryResponse>>,
+    last_trade_prices: Mutex<HashMap<U256, Decimal>>,
+}
+
+impl MockMarketData {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the book served for its `asset_id`, replacing any previous one.
+    pub fn set_order_book(&self, book: OrderBookSummaryResponse) {
+        lock(&self.books).insert(book.asset_id, book);
+    }
+
+    /// Removes the book for `token_id`, as if its market had no order book.
+    pub fn remove_order_book(&self, token_id: U256) {
+        lock(&self.books).remove(&token_id);
+    }
+
+    /// Sets the last trade price for `token_id`, taking precedence over the book's
+    /// `last_trade_price`.
+    pub fn set_last_trade_price(&self, token_id: U256, price: Decimal) {
+        lock(&self.last_trade_prices).insert(token_id, price);
+    }
+
+    fn book(&self, token_id: U256) -> Option<OrderBookSummaryResponse> {
+        lock(&self.books).get(&token_id).cloned()
+    }
+}
+
+#[async_trait]
+impl ClobMarketData for MockMarketData {
+    async fn get_order_book(
+        &self,
+        token_id: U256,
+        depth: Option<usize>,
+    ) -> Result<Option<OrderBookSummaryResponse>> {
+        let mut book = self.book(token_id);
+        if let (Some(book), Some(depth)) = (book.as_mut(), depth) {
+            truncate_book(book, depth);
+        }
+        Ok(book)
+    }
+
+    async fn get_midpoint(&self, token_id: U256) -> Result<Option<Decimal>> {
+        let bbo = self.get_bbo(token_id).await?;
+        Ok(bbo
+            .best_bid
+            .zip(bbo.best_ask)
+            .map(|(bid, ask)| (bid + ask) / Decimal::TWO))
+    }
+
+    async fn get_bbo(&self, token_id: U256) -> Result<Bbo> {
+        Ok(self
+            .book(token_id)
+            .as_ref()
+            .map_or_else(Bbo::default, Bbo::from_book))
+    }
+
+    async fn get_last_trade_price(&self, token_id: U256) -> Result<Option<Decimal>> {
+        if let Some(price) = lock(&self.last_trade_prices).get(&token_id) {
+            return Ok(Some(*price));
+        }
+        Ok(self.book(token_id).and_then(|book| book.last_trade_price))
+    }
+}
+
+/// Locks `mutex`, recovering the data if a panicking test poisoned it.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
@@ -1,18 +1,43 @@
 //! Lean hot-path client focused on fast limit order placement.
 //!
-//! This module intentionally implements only the critical `/order` flow:
+//! The core is the `/order` flow:
 //! - bootstrap credentials via L1 auth
 //! - build + sign limit orders
 //! - submit signed orders with L2 headers
 //!
-//! Additional REST-backed policy modes are modeled but not yet implemented.
+//! Around it sit the helpers a trading loop needs without pulling in the full CLOB client:
+//! - order books, prices, midpoints and market metadata
+//! - cancels, order tracking and replacement
+//! - positions, trade export, rewards and notifications
+//! - a WebSocket order watcher and live best bid and offer
+//! - mocks of the order and market data traits for tests
+//!
+//! Fetching tick size, neg-risk and fee rate per market is modeled but not yet implemented.
 
 mod client;
 mod config;
+mod market_data;
+mod mock;
+mod orders;
 mod policy;
+mod salt;
 mod types;
 
 pub use client::HotPathClient;
-pub use config::{HotPathConfig, RawHotPathSigningConfig};
-pub use policy::{FixedOrFetch, HotPathPolicies, TimePolicy};
-pub use types::{LimitOrderOverrides, LimitOrderRequest, SignatureTypeInput};
+pub use config::{
+    FUNDER_VAR, HOST_VAR, HotPathConfig, Http2Settings, RawHotPathSigningConfig, SIGNATURE_TYPE_VAR,
+};
+pub use market_data::ClobMarketData;
+pub use mock::{MockCall, MockClobClient, MockMarketData};
+pub use orders::ClobOrders;
+pub use policy::{
+    AuthLayer, ClockTolerance, FixedOrFetch, GTD_EXPIRATION_BUFFER_SECS, GtdExpiration,
+    HotPathPolicies, HotPathPoliciesBuilder, NotionalRounding, RetryPolicy, TimePolicy,
+    TimestampSource,
+};
+pub use salt::{MonotonicSalt, SaltSource, TimeRandomSalt};
+pub use types::{
+    Bbo, CredentialMode, CredentialsOrigin, ExportFormat, LimitOrderOverrides, LimitOrderRequest,
+    MarketsPage, Notification, OrderDebug, OrderSizeLimits, OrderStatus, Position, RewardInfo,
+    SignatureTypeInput, TradingReadiness,
+};
@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+
+use crate::Result;
+use crate::clob::types::response::{CancelOrdersResponse, PostOrderResponse};
+use crate::hotpath::{HotPathClient, LimitOrderOverrides, LimitOrderRequest};
+
+/// Order-entry operations of a [`HotPathClient`].
+///
+/// Write strategies against this trait instead of the concrete client so they can be unit
+/// tested with [`MockClobClient`](crate::hotpath::MockClobClient), without a server.
+#[async_trait]
+pub trait ClobOrders: Send + Sync {
+    /// See [`HotPathClient::post_limit_order`].
+    async fn post_limit_order(&self, request: &LimitOrderRequest) -> Result<PostOrderResponse>;
+
+    /// See [`HotPathClient::post_limit_order_with_overrides`].
+    async fn post_limit_order_with_overrides(
+        &self,
+        request: &LimitOrderRequest,
+        overrides: LimitOrderOverrides,
+    ) -> Result<PostOrderResponse>;
+
+    /// See [`HotPathClient::post_limit_orders`].
+    async fn post_limit_orders(
+        &self,
+        requests: &[LimitOrderRequest],
+    ) -> Result<Vec<Result<PostOrderResponse>>>;
+
+    /// See [`HotPathClient::cancel_order`].
+    async fn cancel_order(&self, order_id: &str) -> Result<CancelOrdersResponse>;
+}
+
+#[async_trait]
+impl ClobOrders for HotPathClient {
+    async fn post_limit_order(&self, request: &LimitOrderRequest) -> Result<PostOrderResponse> {
+        HotPathClient::post_limit_order(self, request).await
+    }
+
+    async fn post_limit_order_with_overrides(
+        &self,
+        request: &LimitOrderRequest,
+        overrides: LimitOrderOverrides,
+    ) -> Result<PostOrderResponse> {
+        HotPathClient::post_limit_order_with_overrides(self, request, overrides).await
+    }
+
+    async fn post_limit_orders(
+        &self,
+        requests: &[LimitOrderRequest],
+    ) -> Result<Vec<Result<PostOrderResponse>>> {
+        HotPathClient::post_limit_orders(self, requests).await
+    }
+
+    async fn cancel_order(&self, order_id: &str) -> Result<CancelOrdersResponse> {
+        HotPathClient::cancel_order(self, order_id).await
+    }
+}
@@ -1,13 +1,27 @@
+use std::time::Duration;
+
 use crate::Result;
 use crate::clob::types::TickSize;
 use crate::error::Error;
+use crate::types::Decimal;
+
+/// Share precision of current markets, in decimal places.
+const DEFAULT_LOT_SIZE_SCALE: u32 = 2;
+/// Decimal places of USDC, the finest precision order amounts can carry.
+pub(crate) const USDC_DECIMALS: u32 = 6;
+/// Decimal places the CLOB accepts on the USDC a buy pays, its maker amount.
+pub(crate) const BUY_NOTIONAL_DECIMALS: u32 = 2;
+/// Decimal places the CLOB accepts on the USDC a sell receives, its taker amount.
+pub(crate) const SELL_NOTIONAL_DECIMALS: u32 = 4;
+/// Seconds beyond which a GTD expiration must lie in the future for the exchange to accept it.
+pub const GTD_EXPIRATION_BUFFER_SECS: i64 = 60;
 
 /// Policy wrapper for values that can either be fixed or fetched/cached.
 ///
 /// `FetchAndCache` is intentionally modeled now for future expansion,
 /// but only `Fixed` is currently implemented in `hotpath`.
 #[non_exhaustive]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum FixedOrFetch<T> {
     Fixed(T),
     FetchAndCache,
@@ -17,9 +31,9 @@ impl<T: Copy> FixedOrFetch<T> {
     pub(crate) fn resolve_fixed(self, field: &str) -> Result<T> {
         match self {
             FixedOrFetch::Fixed(value) => Ok(value),
-            FixedOrFetch::FetchAndCache => Err(Error::validation(format!(
-                "{field} policy FetchAndCache is not implemented in hotpath yet"
-            ))),
+            FixedOrFetch::FetchAndCache => {
+                Err(Error::unsupported(format!("{field} policy FetchAndCache")))
+            }
         }
     }
 }
@@ -27,34 +41,196 @@ impl<T: Copy> FixedOrFetch<T> {
 /// Time policy used for L1/L2 header timestamps.
 ///
 /// `Fixed` means "no `/time` call" and uses local unix timestamp.
+/// `FetchAndCache` corrects L2 header timestamps by the server clock offset cached by
+/// [`HotPathClient::sync_clock`](crate::hotpath::HotPathClient::sync_clock), falling back to
+/// local time until the first sync. L1 headers, signed while creating or deriving
+/// credentials, use the server time read from `/time` just before signing.
 #[non_exhaustive]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum TimePolicy {
     Fixed,
     FetchAndCache,
 }
 
-impl TimePolicy {
-    pub(crate) fn ensure_supported(self) -> Result<()> {
-        match self {
-            TimePolicy::Fixed => Ok(()),
-            TimePolicy::FetchAndCache => Err(Error::validation(
-                "time policy FetchAndCache is not implemented in hotpath yet",
-            )),
+/// Where the timestamp on the most recent L2 headers came from.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TimestampSource {
+    /// A caller-supplied timestamp override.
+    Override = 0,
+    /// The raw local clock.
+    Local = 1,
+    /// The local clock corrected by the cached server clock offset.
+    ServerOffset = 2,
+}
+
+/// Handling of GTD expirations within [`GTD_EXPIRATION_BUFFER_SECS`] of now.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum GtdExpiration {
+    /// Reject the order before signing.
+    #[default]
+    Reject,
+    /// Move the expiration forward to the earliest time the exchange accepts. Only applies to
+    /// an expiration that was set; a GTD order without one is still rejected.
+    BumpForward,
+}
+
+/// Rounding of an order's USDC amount, `price * size`, when it has more decimal places than
+/// the exchange accepts.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum NotionalRounding {
+    /// Truncate for both sides: buys never pay more than `price * size`, but sells may
+    /// receive slightly less.
+    #[default]
+    Truncate,
+    /// Round each side's USDC amount down to the precision the CLOB accepts for that side:
+    /// buys round the USDC paid (maker amount) to 2 decimal places and sells round the USDC
+    /// received (taker amount) to 4, so neither side's USDC amount exceeds `price * size`.
+    /// An order whose value rounds to zero is rejected.
+    SideAware,
+}
+
+/// Authentication layer a header timestamp is generated for.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AuthLayer {
+    /// L1 (private key) headers used to create or derive API credentials.
+    L1,
+    /// L2 (API key HMAC) headers used on order requests.
+    L2,
+}
+
+/// Accepted skew between header timestamps and server time, per [`AuthLayer`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ClockTolerance {
+    /// Tolerance for L1 header timestamps, in seconds.
+    pub l1_secs: i64,
+    /// Tolerance for L2 header timestamps, in seconds.
+    pub l2_secs: i64,
+}
+
+impl ClockTolerance {
+    #[must_use]
+    pub const fn new(l1_secs: i64, l2_secs: i64) -> Self {
+        Self { l1_secs, l2_secs }
+    }
+
+    #[must_use]
+    pub const fn for_layer(self, layer: AuthLayer) -> i64 {
+        match layer {
+            AuthLayer::L1 => self.l1_secs,
+            AuthLayer::L2 => self.l2_secs,
+        }
+    }
+}
+
+impl Default for ClockTolerance {
+    fn default() -> Self {
+        Self {
+            l1_secs: 30,
+            l2_secs: 30,
+        }
+    }
+}
+
+/// Retry-with-exponential-backoff settings for one class of requests.
+///
+/// Only [retryable](crate::error::Error::is_retryable) failures are retried. The wait before
+/// retry `n` (from zero) is `initial_backoff * 2^n`, capped at `max_backoff`.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; zero disables retrying.
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Never retry.
+    pub const NONE: Self = Self::new(0, Duration::ZERO, Duration::ZERO);
+
+    #[must_use]
+    pub const fn new(max_retries: u32, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        Self {
+            max_retries,
+            initial_backoff,
+            max_backoff,
         }
     }
+
+    /// Returns the wait before retry `attempt`, counting from zero.
+    pub(crate) fn backoff(self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2_u32.saturating_pow(attempt))
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::NONE
+    }
 }
 
 /// Defaults used by the hot-path order flow.
-#[derive(Clone, Copy, Debug)]
+///
+/// Construct with [`Self::fixed`] or [`Self::builder`]; new knobs are added over time, so the
+/// struct cannot be built with a literal outside this crate.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct HotPathPolicies {
     pub tick_size: FixedOrFetch<TickSize>,
     pub neg_risk: FixedOrFetch<bool>,
     pub fee_rate_bps: FixedOrFetch<u32>,
     pub time: TimePolicy,
+    /// Minimum order value (`price * size`, in USDC) accepted before signing.
+    ///
+    /// Independent of the market's minimum share size; low-priced markets hit this first.
+    /// Set with [`HotPathPoliciesBuilder::min_order_value`].
+    pub min_order_value: Option<Decimal>,
+    /// Maximum decimal places of an order's size, i.e. the market's share precision.
+    /// Set with [`HotPathPoliciesBuilder::lot_size_scale`].
+    pub lot_size_scale: u32,
+    /// What to do with GTD expirations the exchange would reject as too soon.
+    /// GTD orders without an expiration are always rejected. Set with
+    /// [`HotPathPoliciesBuilder::gtd_expiration`].
+    pub gtd_expiration: GtdExpiration,
+    /// How the USDC amount is rounded to the exchange's precision.
+    pub notional_rounding: NotionalRounding,
 }
 
 impl HotPathPolicies {
+    /// Policies with every market parameter fixed up front and local clock timestamps.
+    #[must_use]
+    pub const fn fixed(tick_size: TickSize, neg_risk: bool, fee_rate_bps: u32) -> Self {
+        Self {
+            tick_size: FixedOrFetch::Fixed(tick_size),
+            neg_risk: FixedOrFetch::Fixed(neg_risk),
+            fee_rate_bps: FixedOrFetch::Fixed(fee_rate_bps),
+            time: TimePolicy::Fixed,
+            min_order_value: None,
+            lot_size_scale: DEFAULT_LOT_SIZE_SCALE,
+            gtd_expiration: GtdExpiration::Reject,
+            notional_rounding: NotionalRounding::Truncate,
+        }
+    }
+
+    /// Starts a [`HotPathPoliciesBuilder`], for overriding individual policies.
+    pub fn builder() -> HotPathPoliciesBuilder {
+        HotPathPoliciesBuilder::default()
+    }
+
+    /// Like [`Self::fixed`], but takes the tick size as a decimal string such as `"0.01"`,
+    /// as found in app-level config; see [`TickSize::try_from_decimal_str`].
+    pub fn fixed_from_raw(tick_size: &str, neg_risk: bool, fee_rate_bps: u32) -> Result<Self> {
+        let tick_size = TickSize::try_from_decimal_str(tick_size)?;
+        Ok(Self::fixed(tick_size, neg_risk, fee_rate_bps))
+    }
+
     pub(crate) fn default_tick_size(self) -> Result<TickSize> {
         self.tick_size.resolve_fixed("tick_size")
     }
@@ -68,10 +244,208 @@ impl HotPathPolicies {
     }
 
     pub(crate) fn validate(self) -> Result<()> {
-        self.time.ensure_supported()?;
         let _ = self.default_tick_size()?;
         let _ = self.default_neg_risk()?;
         let _ = self.default_fee_rate_bps()?;
+        if self.lot_size_scale > USDC_DECIMALS {
+            return Err(Error::validation(format!(
+                "lot_size_scale must be at most {USDC_DECIMALS}, got {}",
+                self.lot_size_scale
+            )));
+        }
+        if let Some(min_order_value) = self.min_order_value
+            && min_order_value.is_sign_negative()
+        {
+            return Err(Error::validation(format!(
+                "min_order_value must not be negative, got {min_order_value}"
+            )));
+        }
         Ok(())
     }
 }
+
+/// Builder for [`HotPathPolicies`], created with [`HotPathPolicies::builder`].
+///
+/// Every setting starts as in `HotPathPolicies::fixed(TickSize::Hundredth, false, 0)`.
+/// [`Self::build`] runs the same validation as client construction, so invalid settings
+/// are reported up front.
+#[must_use]
+#[derive(Clone, Copy, Debug)]
+pub struct HotPathPoliciesBuilder {
+    policies: HotPathPolicies,
+}
+
+impl Default for HotPathPoliciesBuilder {
+    fn default() -> Self {
+        Self {
+            policies: HotPathPolicies::fixed(TickSize::Hundredth, false, 0),
+        }
+    }
+}
+
+impl HotPathPoliciesBuilder {
+    pub const fn fixed_tick_size(mut self, tick_size: TickSize) -> Self {
+        self.policies.tick_size = FixedOrFetch::Fixed(tick_size);
+        self
+    }
+
+    pub const fn fixed_neg_risk(mut self, neg_risk: bool) -> Self {
+        self.policies.neg_risk = FixedOrFetch::Fixed(neg_risk);
+        self
+    }
+
+    pub const fn fixed_fee_rate_bps(mut self, fee_rate_bps: u32) -> Self {
+        self.policies.fee_rate_bps = FixedOrFetch::Fixed(fee_rate_bps);
+        self
+    }
+
+    pub const fn time(mut self, time: TimePolicy) -> Self {
+        self.policies.time = time;
+        self
+    }
+
+    pub const fn min_order_value(mut self, min_order_value: Decimal) -> Self {
+        self.policies.min_order_value = Some(min_order_value);
+        self
+    }
+
+    pub const fn lot_size_scale(mut self, lot_size_scale: u32) -> Self {
+        self.policies.lot_size_scale = lot_size_scale;
+        self
+    }
+
+    pub const fn gtd_expiration(mut self, gtd_expiration: GtdExpiration) -> Self {
+        self.policies.gtd_expiration = gtd_expiration;
+        self
+    }
+
+    pub const fn notional_rounding(mut self, notional_rounding: NotionalRounding) -> Self {
+        self.policies.notional_rounding = notional_rounding;
+        self
+    }
+
+    /// Validates and returns the policies.
+    pub fn build(self) -> Result<HotPathPolicies> {
+        self.policies.validate()?;
+        Ok(self.policies)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{Kind, Unsupported};
+
+    #[test]
+    fn fixed_should_validate_and_resolve_defaults() {
+        let policies = HotPathPolicies::fixed(TickSize::Hundredth, true, 15);
+
+        policies.validate().unwrap();
+        assert!(
+            matches!(policies.default_tick_size().unwrap(), TickSize::Hundredth),
+            "tick_size should be fixed to a hundredth"
+        );
+        assert!(
+            policies.default_neg_risk().unwrap(),
+            "neg_risk should be fixed to true"
+        );
+        assert_eq!(policies.default_fee_rate_bps().unwrap(), 15);
+        assert!(
+            matches!(policies.time, TimePolicy::Fixed),
+            "time should be fixed"
+        );
+        assert_eq!(policies.min_order_value, None);
+        assert_eq!(policies.lot_size_scale, 2);
+    }
+
+    #[test]
+    fn retry_backoff_should_double_up_to_max() {
+        let policy = RetryPolicy::new(5, Duration::from_millis(100), Duration::from_millis(300));
+
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(2), Duration::from_millis(300));
+        assert_eq!(policy.backoff(40), Duration::from_millis(300));
+    }
+
+    #[test]
+    fn fixed_from_raw_should_map_decimal_tick_size() {
+        let policies = HotPathPolicies::fixed_from_raw("0.001", false, 0).unwrap();
+        assert!(
+            matches!(policies.default_tick_size().unwrap(), TickSize::Thousandth),
+            "tick_size should be fixed to a thousandth"
+        );
+
+        let err = HotPathPolicies::fixed_from_raw("0.05", false, 0).unwrap_err();
+        assert_eq!(err.kind(), Kind::Validation);
+    }
+
+    #[test]
+    fn builder_should_match_manual_construction() {
+        let built = HotPathPolicies::builder()
+            .fixed_tick_size(TickSize::Thousandth)
+            .fixed_neg_risk(true)
+            .fixed_fee_rate_bps(15)
+            .time(TimePolicy::FetchAndCache)
+            .min_order_value(Decimal::ONE)
+            .lot_size_scale(4)
+            .gtd_expiration(GtdExpiration::BumpForward)
+            .notional_rounding(NotionalRounding::SideAware)
+            .build()
+            .unwrap();
+
+        let mut manual = HotPathPolicies::fixed(TickSize::Thousandth, true, 15);
+        manual.time = TimePolicy::FetchAndCache;
+        manual.min_order_value = Some(Decimal::ONE);
+        manual.lot_size_scale = 4;
+        manual.gtd_expiration = GtdExpiration::BumpForward;
+        manual.notional_rounding = NotionalRounding::SideAware;
+
+        assert_eq!(built, manual);
+        assert_eq!(
+            HotPathPolicies::builder().build().unwrap(),
+            HotPathPolicies::fixed(TickSize::Hundredth, false, 0)
+        );
+    }
+
+    #[test]
+    fn builder_should_validate_lot_size_scale() {
+        let err = HotPathPolicies::builder()
+            .lot_size_scale(7)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind(), Kind::Validation);
+    }
+
+    fn assert_unsupported(result: Result<()>, feature: &str) {
+        let err = result.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Unsupported);
+        assert_eq!(err.downcast_ref::<Unsupported>().unwrap().feature, feature);
+    }
+
+    #[test]
+    fn fetch_and_cache_policies_should_be_unsupported() {
+        let fixed = HotPathPolicies::fixed(TickSize::Hundredth, false, 0);
+
+        let mut policies = fixed;
+        policies.tick_size = FixedOrFetch::FetchAndCache;
+        assert_unsupported(policies.validate(), "tick_size policy FetchAndCache");
+
+        let mut policies = fixed;
+        policies.neg_risk = FixedOrFetch::FetchAndCache;
+        assert_unsupported(policies.validate(), "neg_risk policy FetchAndCache");
+
+        let mut policies = fixed;
+        policies.fee_rate_bps = FixedOrFetch::FetchAndCache;
+        assert_unsupported(policies.validate(), "fee_rate_bps policy FetchAndCache");
+    }
+
+    #[test]
+    fn time_fetch_and_cache_should_be_supported() {
+        let mut policies = HotPathPolicies::fixed(TickSize::Hundredth, false, 0);
+        policies.time = TimePolicy::FetchAndCache;
+
+        policies.validate().unwrap();
+    }
+}
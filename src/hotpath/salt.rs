@@ -0,0 +1,116 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rand::Rng as _;
+
+/// Source of the salt that makes each signed order unique.
+///
+/// Implement this to draw salts from an external coordinator, e.g. to rule out collisions
+/// between processes signing for the same funder.
+pub trait SaltSource: fmt::Debug + Send + Sync + 'static {
+    /// Returns the next salt. Only the low 53 bits are used, since the backend parses the
+    /// salt as an IEEE 754 double.
+    fn next_salt(&self) -> u64;
+}
+
+/// Default [`SaltSource`]: the current unix time scaled by a random factor, matching the
+/// other Polymarket clients.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TimeRandomSalt;
+
+impl SaltSource for TimeRandomSalt {
+    #[expect(
+        clippy::float_arithmetic,
+        reason = "We are not concerned with precision for the seed"
+    )]
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "We are not concerned with truncation for a seed"
+    )]
+    #[expect(clippy::cast_sign_loss, reason = "We only need positive integers")]
+    fn next_salt(&self) -> u64 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time went backwards");
+        let seconds = now.as_secs_f64();
+        let random = rand::rng().random::<f64>();
+        (seconds * random).round() as u64
+    }
+}
+
+/// The salt bits the backend keeps, see [`SaltSource::next_salt`].
+const SALT_MASK: u64 = (1 << 53) - 1;
+
+/// [`SaltSource`] that never repeats a salt: a counter added to a random starting point,
+/// masked to 53 bits.
+///
+/// The random start keeps separate processes apart, while the counter guarantees that one
+/// instance yields 2^53 distinct salts however fast it is called. Clones of a
+/// [`HotPathClient`](crate::hotpath::HotPathClient) share its salt source, so clone one
+/// client rather than building several when they sign for the same funder.
+#[derive(Debug)]
+pub struct MonotonicSalt {
+    start: u64,
+    counter: AtomicU64,
+}
+
+impl MonotonicSalt {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::starting_at(rand::rng().random())
+    }
+
+    fn starting_at(start: u64) -> Self {
+        Self {
+            start: start & SALT_MASK,
+            counter: AtomicU64::new(0),
+        }
+    }
+}
+
+impl Default for MonotonicSalt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SaltSource for MonotonicSalt {
+    fn next_salt(&self) -> u64 {
+        let count = self.counter.fetch_add(1, Ordering::Relaxed);
+        self.start.wrapping_add(count) & SALT_MASK
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::iter;
+
+    use super::*;
+
+    #[test]
+    fn monotonic_salt_should_not_repeat() {
+        let source = MonotonicSalt::new();
+
+        let salts: HashSet<u64> = iter::repeat_with(|| source.next_salt())
+            .take(1_000_000)
+            .collect();
+
+        assert_eq!(salts.len(), 1_000_000, "salts should be unique");
+        assert!(
+            salts.iter().all(|&salt| salt <= SALT_MASK),
+            "salts should fit in 53 bits"
+        );
+    }
+
+    #[test]
+    fn monotonic_salt_should_wrap_within_53_bits() {
+        let source = MonotonicSalt::starting_at(SALT_MASK - 1);
+
+        let salts: Vec<u64> = iter::repeat_with(|| source.next_salt()).take(3).collect();
+
+        assert_eq!(salts, [SALT_MASK - 1, SALT_MASK, 0]);
+    }
+}
@@ -1,11 +1,23 @@
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::iter;
 use std::str::FromStr;
+use std::time::Duration;
 
 use chrono::{DateTime, Utc};
+use rand::Rng as _;
+use serde::{Deserialize, Deserializer, de};
+use serde_json::Value;
 
 use crate::Result;
-use crate::clob::types::{OrderType, Side, SignatureType, TickSize};
+use crate::auth::Credentials;
+use crate::clob::types::response::{
+    CurrentRewardResponse, MarketResponse, MarketRewardResponse, NotificationResponse,
+    OpenOrderResponse, OrderBookSummaryResponse, Page, TradeResponse,
+};
+use crate::clob::types::{OrderStatusType, OrderType, Side, SignatureType, TickSize, TraderSide};
 use crate::error::Error;
-use crate::types::{Address, Decimal, U256};
+use crate::types::{Address, B256, ChainId, Decimal, U256};
 
 /// Signature type parser for config-style string inputs.
 #[non_exhaustive]
@@ -49,6 +61,12 @@ pub struct LimitOrderRequest {
     pub size: Decimal,
     pub nonce: Option<u64>,
     pub expiration: Option<DateTime<Utc>>,
+    /// Lifetime of a GTD order, turned into an expiration when the order is signed; see
+    /// [`Self::with_expires_in`]. Ignored when `expiration` is set.
+    pub expires_in: Option<Duration>,
+    /// Restricts who may fill the order. `None` or [`Address::ZERO`] leaves the order open to
+    /// anyone; any other address makes it a private order only that taker can fill, which is
+    /// only meaningful for resting GTC/GTD orders.
     pub taker: Option<Address>,
     pub order_type: Option<OrderType>,
     pub post_only: Option<bool>,
@@ -64,11 +82,158 @@ impl LimitOrderRequest {
             size,
             nonce: None,
             expiration: None,
+            expires_in: None,
             taker: None,
             order_type: None,
             post_only: None,
         }
     }
+
+    /// Makes this a GTD order expiring at `secs` seconds since the unix epoch.
+    ///
+    /// The timestamp must be positive and in the future.
+    pub fn with_expiration_unix(mut self, secs: i64) -> Result<Self> {
+        if secs <= 0 {
+            return Err(Error::validation(format!(
+                "expiration must be a positive unix timestamp, got {secs}"
+            )));
+        }
+        if secs <= Utc::now().timestamp() {
+            return Err(Error::validation(format!(
+                "expiration {secs} must be in the future"
+            )));
+        }
+
+        let expiration = DateTime::<Utc>::from_timestamp(secs, 0).ok_or(Error::validation(
+            format!("Unable to represent expiration {secs} as a timestamp"),
+        ))?;
+
+        self.expiration = Some(expiration);
+        self.order_type = Some(OrderType::GTD);
+        Ok(self)
+    }
+
+    /// Makes this a GTD order expiring `lifetime` after it is signed.
+    ///
+    /// The expiration is computed at signing time against the exchange's clock when
+    /// [`TimePolicy::FetchAndCache`](crate::hotpath::TimePolicy::FetchAndCache) has a synced
+    /// offset, and against local time otherwise, so the order lives for `lifetime` as the
+    /// exchange sees it.
+    #[must_use]
+    pub fn with_expires_in(mut self, lifetime: Duration) -> Self {
+        self.expiration = None;
+        self.expires_in = Some(lifetime);
+        self.order_type = Some(OrderType::GTD);
+        self
+    }
+
+    /// Sets the order type, failing if it conflicts with an earlier
+    /// [`Self::with_post_only`].
+    pub fn with_order_type(mut self, order_type: OrderType) -> Result<Self> {
+        self.order_type = Some(order_type);
+        self.validate()?;
+        Ok(self)
+    }
+
+    /// Marks the order post-only, failing if it conflicts with an earlier
+    /// [`Self::with_order_type`].
+    pub fn with_post_only(mut self, post_only: bool) -> Result<Self> {
+        self.post_only = Some(post_only);
+        self.validate()?;
+        Ok(self)
+    }
+
+    /// Checks field combinations that are invalid regardless of the market, such as
+    /// `post_only` on a FOK or FAK order.
+    ///
+    /// The `with_*` setters run this on every call; use it directly after setting fields by
+    /// hand. Market-dependent checks still run when the order is signed.
+    pub fn validate(&self) -> Result<()> {
+        let is_immediate = self
+            .order_type
+            .as_ref()
+            .is_some_and(|order_type| !matches!(order_type, OrderType::GTC | OrderType::GTD));
+        if self.post_only == Some(true) && is_immediate {
+            return Err(Error::validation(
+                "postOnly is only supported for GTC and GTD orders",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Splits this order into `children` orders of equal size.
+    ///
+    /// Child sizes are whole multiples of `10^-lot_size_scale` and sum exactly to
+    /// [`Self::size`]; any remainder goes to the last child. Every other field is copied
+    /// from the parent. Pass the client's configured lot scale, e.g.
+    /// `client.policies().lot_size_scale`.
+    pub fn split(&self, children: usize, lot_size_scale: u32) -> Result<Vec<Self>> {
+        self.split_by_weights(&vec![1; children], lot_size_scale)
+    }
+
+    /// Like [`Self::split`], but with randomized child sizes so the slices are harder to
+    /// spot on the book.
+    ///
+    /// Each child gets at least one lot; the remaining lots are distributed by random
+    /// weights.
+    pub fn split_randomized(&self, children: usize, lot_size_scale: u32) -> Result<Vec<Self>> {
+        let mut rng = rand::rng();
+        let weights: Vec<i128> = iter::repeat_with(|| rng.random_range(1..=100))
+            .take(children)
+            .collect();
+        self.split_by_weights(&weights, lot_size_scale)
+    }
+
+    fn split_by_weights(&self, weights: &[i128], lot_size_scale: u32) -> Result<Vec<Self>> {
+        let size = self.size;
+        if weights.is_empty() {
+            return Err(Error::validation("Unable to split order into 0 children"));
+        }
+        if size <= Decimal::ZERO {
+            return Err(Error::validation(format!(
+                "Unable to split order: size {size} must be positive"
+            )));
+        }
+        if size.scale() > lot_size_scale {
+            return Err(Error::validation(format!(
+                "Unable to split order: Size {size} has {} decimal places. Maximum lot size is {lot_size_scale}",
+                size.scale()
+            )));
+        }
+
+        let mut lots = size;
+        lots.rescale(lot_size_scale);
+        let total = lots.mantissa();
+        let count = i128::try_from(weights.len()).map_err(|e| Error::validation(e.to_string()))?;
+        if total < count {
+            return Err(Error::validation(format!(
+                "Unable to split order: size {size} is smaller than {count} lots"
+            )));
+        }
+
+        // One lot per child up front, so no child can round down to zero.
+        let spare = total - count;
+        let weight_sum: i128 = weights.iter().sum();
+        let last = weights.len() - 1;
+        let mut allocated = 0;
+
+        Ok(weights
+            .iter()
+            .enumerate()
+            .map(|(index, weight)| {
+                let extra = if index == last {
+                    spare - allocated
+                } else {
+                    spare * weight / weight_sum
+                };
+                allocated += extra;
+
+                let mut child = self.clone();
+                child.size = Decimal::from_i128_with_scale(1 + extra, lot_size_scale);
+                child
+            })
+            .collect())
+    }
 }
 
 /// Per-order overrides on top of fixed hot-path defaults.
@@ -78,6 +243,9 @@ pub struct LimitOrderOverrides {
     pub neg_risk: Option<bool>,
     pub fee_rate_bps: Option<u32>,
     pub timestamp: Option<i64>,
+    /// Signs this order with a different [`SignatureType`] than the client's. An
+    /// [`SignatureType::Eoa`] order is made by the signer itself rather than the funder.
+    pub signature_type: Option<SignatureType>,
 }
 
 impl LimitOrderOverrides {
@@ -104,6 +272,419 @@ impl LimitOrderOverrides {
         self.timestamp = Some(timestamp);
         self
     }
+
+    #[must_use]
+    pub const fn with_signature_type(mut self, signature_type: SignatureType) -> Self {
+        self.signature_type = Some(signature_type);
+        self
+    }
+}
+
+/// How a [`HotPathClient`](crate::hotpath::HotPathClient)'s API credentials were obtained.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CredentialsOrigin {
+    /// Passed in by the caller.
+    Supplied,
+    /// Freshly created via L1 auth.
+    Created,
+    /// Creation was rejected, so the existing key was derived via L1 auth.
+    Derived,
+}
+
+/// How [`HotPathClient::bootstrap`](crate::hotpath::HotPathClient::bootstrap) and
+/// [`HotPathClient::refresh_credentials`](crate::hotpath::HotPathClient::refresh_credentials)
+/// obtain API credentials via L1 auth.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CredentialMode {
+    /// Create a key, deriving the existing one if creation is rejected.
+    #[default]
+    CreateOrDerive,
+    /// Only create a key; fails if the key already exists.
+    CreateOnly,
+    /// Only derive the existing key; never creates one.
+    DeriveOnly,
+}
+
+/// A page of CLOB markets; pass `next_cursor` back in to fetch the following page.
+pub type MarketsPage = Page<MarketResponse>;
+
+/// An account event such as a fill or market resolution.
+pub type Notification = NotificationResponse;
+
+/// Best bid and offer for a single token.
+///
+/// Either side is `None` when that side of the book has no resting orders, which is the
+/// normal state for freshly-listed markets.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Bbo {
+    pub best_bid: Option<Decimal>,
+    pub best_ask: Option<Decimal>,
+}
+
+impl Bbo {
+    pub(crate) fn from_book(book: &OrderBookSummaryResponse) -> Self {
+        Self {
+            best_bid: book.bids.iter().map(|level| level.price).max(),
+            best_ask: book.asks.iter().map(|level| level.price).min(),
+        }
+    }
+
+    /// Returns the price to move a resting post-only order on `side`, currently at
+    /// `current`, to so it stays passive while leading the book.
+    ///
+    /// When another order is priced better than `current`, the result steps
+    /// `min_improvement_ticks` ticks (at least one) inside it; otherwise `current` already
+    /// leads and is kept. Returns `None` when that price would cross or touch the opposite
+    /// side, or falls outside the valid `(0, 1)` price range, as a post-only order there
+    /// would be rejected.
+    #[must_use]
+    pub fn passive_price(
+        &self,
+        side: Side,
+        current: Decimal,
+        tick_size: TickSize,
+        min_improvement_ticks: u32,
+    ) -> Option<Decimal> {
+        let tick = tick_size.as_decimal();
+        let improvement = tick * Decimal::from(min_improvement_ticks.max(1));
+
+        let price = match side {
+            Side::Buy => match self.best_bid {
+                Some(bid) if bid > current => bid + improvement,
+                _ => current,
+            },
+            Side::Sell => match self.best_ask {
+                Some(ask) if ask < current => ask - improvement,
+                _ => current,
+            },
+            Side::Unknown => return None,
+        };
+
+        let crosses = match side {
+            Side::Buy => self.best_ask.is_some_and(|ask| price >= ask),
+            _ => self.best_bid.is_some_and(|bid| price <= bid),
+        };
+        let in_range = price >= tick && price <= Decimal::ONE - tick;
+
+        (in_range && !crosses).then_some(price)
+    }
+}
+
+/// Sorts each side of `book` best price first and keeps the top `depth` levels.
+pub(crate) fn truncate_book(book: &mut OrderBookSummaryResponse, depth: usize) {
+    book.bids.sort_by_key(|level| Reverse(level.price));
+    book.bids.truncate(depth);
+    book.asks.sort_by_key(|level| level.price);
+    book.asks.truncate(depth);
+}
+
+/// Order size bounds a market publishes, in shares; see
+/// [`HotPathClient::order_size_limits`](crate::hotpath::HotPathClient::order_size_limits).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OrderSizeLimits {
+    /// Smallest order size the market accepts.
+    pub min: Decimal,
+    /// Largest order size the market accepts, when it publishes one.
+    pub max: Option<Decimal>,
+}
+
+/// Everything signing a limit order computes, for diffing against other clients such as
+/// `py-clob-client`; see
+/// [`HotPathClient::debug_order_payload`](crate::hotpath::HotPathClient::debug_order_payload).
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrderDebug {
+    pub salt: U256,
+    pub maker_amount: U256,
+    pub taker_amount: U256,
+    /// EIP-712 domain name.
+    pub domain_name: String,
+    /// EIP-712 domain version.
+    pub domain_version: String,
+    pub chain_id: ChainId,
+    /// Exchange contract the order is signed against.
+    pub verifying_contract: Address,
+    /// EIP-712 hash the signature covers.
+    pub signing_hash: B256,
+    /// JSON body `POST /order` would send.
+    pub body: String,
+}
+
+/// Pre-trade account checks; see
+/// [`HotPathClient::check_trading_ready`](crate::hotpath::HotPathClient::check_trading_ready).
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TradingReadiness {
+    /// Whether the CLOB accepted the API credentials.
+    pub credentials_valid: bool,
+    /// Funder collateral balance in base units, as reported by the CLOB. `None` when the
+    /// credentials were rejected and the balance could not be read.
+    pub balance: Option<Decimal>,
+    /// Exchange contracts whose collateral allowance does not cover [`Self::balance`].
+    pub insufficient_allowances: Vec<Address>,
+}
+
+impl TradingReadiness {
+    /// Returns `true` when every check passed and orders can be placed.
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        self.credentials_valid
+            && self.balance.is_some_and(|balance| balance > Decimal::ZERO)
+            && self.insufficient_allowances.is_empty()
+    }
+}
+
+/// Output format of [`HotPathClient::export_trades`](crate::hotpath::HotPathClient::export_trades).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ExportFormat {
+    /// A header row followed by one comma-separated row per trade.
+    Csv,
+    /// One trade object per line, exactly as returned by the API.
+    JsonLines,
+}
+
+/// Column names of the rows written by [`trade_csv_row`].
+pub(crate) const TRADE_CSV_HEADER: &str = "id,match_time,market,asset_id,side,outcome,price,size,fee_rate_bps,status,trader_side,transaction_hash";
+
+/// Formats `trade` as a CSV row matching [`TRADE_CSV_HEADER`].
+pub(crate) fn trade_csv_row(trade: &TradeResponse) -> String {
+    let trader_side = match &trade.trader_side {
+        TraderSide::Taker => "TAKER",
+        TraderSide::Maker => "MAKER",
+        TraderSide::Unknown(other) => other.as_str(),
+    };
+    let fields = [
+        trade.id.clone(),
+        trade.match_time.to_rfc3339(),
+        trade.market.to_string(),
+        trade.asset_id.to_string(),
+        trade.side.to_string(),
+        trade.outcome.clone(),
+        trade.price.to_string(),
+        trade.size.to_string(),
+        trade.fee_rate_bps.to_string(),
+        trade.status.to_string(),
+        trader_side.to_owned(),
+        trade.transaction_hash.to_string(),
+    ];
+
+    fields
+        .iter()
+        .map(|field| csv_field(field))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Quotes `value` if it contains a delimiter, quote or line break.
+fn csv_field(value: &str) -> Cow<'_, str> {
+    if value.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", value.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+/// Liquidity reward program parameters for a single market.
+///
+/// Orders only earn rewards when they rest within `max_spread` of the midpoint and are at
+/// least `min_size` shares.
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardInfo {
+    pub condition_id: B256,
+    pub max_spread: Decimal,
+    pub min_size: Decimal,
+    /// Combined daily reward rate across every active program on the market.
+    pub daily_rate: Decimal,
+}
+
+impl From<CurrentRewardResponse> for RewardInfo {
+    fn from(response: CurrentRewardResponse) -> Self {
+        Self {
+            condition_id: response.condition_id,
+            max_spread: response.rewards_max_spread,
+            min_size: response.rewards_min_size,
+            daily_rate: response
+                .rewards_config
+                .iter()
+                .map(|config| config.rate_per_day)
+                .sum(),
+        }
+    }
+}
+
+impl From<MarketRewardResponse> for RewardInfo {
+    fn from(response: MarketRewardResponse) -> Self {
+        Self {
+            condition_id: response.condition_id,
+            max_spread: response.rewards_max_spread,
+            min_size: response.rewards_min_size,
+            daily_rate: response
+                .rewards_config
+                .iter()
+                .map(|config| config.rate_per_day)
+                .sum(),
+        }
+    }
+}
+
+/// Net position in a single outcome token, aggregated from the account's fills.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Position {
+    pub token_id: U256,
+    /// Net shares held; buys add and sells subtract.
+    pub size: Decimal,
+    /// Average entry price of the shares currently held, or `None` when flat.
+    pub avg_price: Option<Decimal>,
+}
+
+impl Position {
+    #[must_use]
+    pub fn new(token_id: U256) -> Self {
+        Self {
+            token_id,
+            ..Self::default()
+        }
+    }
+
+    /// Applies a single fill using average-cost accounting.
+    ///
+    /// Buys re-weight the average entry price, sells reduce the size at the current average.
+    /// A buy that covers a short only prices the shares bought beyond it.
+    pub fn apply_fill(&mut self, side: Side, size: Decimal, price: Decimal) {
+        match side {
+            Side::Buy => {
+                let held = self.size.max(Decimal::ZERO);
+                // Shares that cover a short close it and carry no entry cost.
+                let opened = (self.size + size).min(size).max(Decimal::ZERO);
+                let cost = self.avg_price.unwrap_or(Decimal::ZERO) * held + price * opened;
+                self.size += size;
+                self.avg_price = (self.size > Decimal::ZERO).then(|| cost / self.size);
+            }
+            Side::Sell => {
+                self.size -= size;
+                if self.size <= Decimal::ZERO {
+                    self.avg_price = None;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Fill progress of a single order, as yielded by [`HotPathClient::watch_order`].
+///
+/// [`HotPathClient::watch_order`]: crate::hotpath::HotPathClient::watch_order
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderStatus {
+    pub order_id: String,
+    pub status: OrderStatusType,
+    pub original_size: Decimal,
+    pub size_matched: Decimal,
+}
+
+impl OrderStatus {
+    /// Size still resting on the book.
+    #[must_use]
+    pub fn remaining_size(&self) -> Decimal {
+        (self.original_size - self.size_matched).max(Decimal::ZERO)
+    }
+
+    #[must_use]
+    pub fn is_filled(&self) -> bool {
+        self.size_matched >= self.original_size
+    }
+
+    /// Whether the order can no longer receive fills.
+    #[must_use]
+    pub fn is_terminal(&self) -> bool {
+        self.is_filled()
+            || matches!(
+                self.status,
+                OrderStatusType::Matched | OrderStatusType::Canceled | OrderStatusType::Unmatched
+            )
+    }
+}
+
+impl From<OpenOrderResponse> for OrderStatus {
+    fn from(order: OpenOrderResponse) -> Self {
+        Self {
+            order_id: order.id,
+            status: order.status,
+            original_size: order.original_size,
+            size_matched: order.size_matched,
+        }
+    }
+}
+
+/// `/midpoint` payload where an empty book yields a missing or empty `mid`.
+#[derive(Debug, Deserialize)]
+pub(crate) struct MidpointQuote {
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub mid: Option<Decimal>,
+}
+
+/// `/price` payload where an empty book yields a missing or empty `price`.
+#[derive(Debug, Deserialize)]
+pub(crate) struct PriceQuote {
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub price: Option<Decimal>,
+}
+
+/// Current order nonce for the authenticated account.
+#[derive(Debug, Deserialize)]
+pub(crate) struct NonceResponse {
+    pub nonce: u32,
+}
+
+/// `/auth/derive-api-key` payload: the credentials plus, when the server reports it, the
+/// order nonce associated with the derived key.
+#[derive(Debug, Deserialize)]
+pub(crate) struct DerivedApiKey {
+    #[serde(flatten)]
+    pub credentials: Credentials,
+    #[serde(default, deserialize_with = "nonce_as_u64")]
+    pub nonce: Option<u64>,
+}
+
+fn nonce_as_u64<'de, D>(deserializer: D) -> std::result::Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) if s.trim().is_empty() => Ok(None),
+        Some(Value::String(s)) => s.trim().parse().map(Some).map_err(de::Error::custom),
+        Some(Value::Number(n)) => n
+            .as_u64()
+            .map(Some)
+            .ok_or_else(|| de::Error::custom(format!("invalid nonce {n}"))),
+        Some(other) => Err(de::Error::custom(format!("invalid nonce {other}"))),
+    }
+}
+
+fn empty_as_none<'de, D>(deserializer: D) -> std::result::Result<Option<Decimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) if s.trim().is_empty() => Ok(None),
+        Some(Value::String(s)) => Decimal::from_str(&s).map(Some).map_err(de::Error::custom),
+        Some(Value::Number(n)) => Decimal::from_str(&n.to_string())
+            .map(Some)
+            .map_err(de::Error::custom),
+        Some(other) => Err(de::Error::custom(format!(
+            "expected a decimal string or number, got {other}"
+        ))),
+    }
 }
 
 impl FromStr for SignatureTypeInput {
@@ -113,3 +694,321 @@ impl FromStr for SignatureTypeInput {
         SignatureTypeInput::parse(s)
     }
 }
+
+impl From<SignatureTypeInput> for SignatureType {
+    fn from(value: SignatureTypeInput) -> Self {
+        value.into_signature_type()
+    }
+}
+
+impl TryFrom<u8> for SignatureTypeInput {
+    type Error = Error;
+
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        match value {
+            0 => Ok(SignatureTypeInput::Eoa),
+            1 => Ok(SignatureTypeInput::Proxy),
+            2 => Ok(SignatureTypeInput::GnosisSafe),
+            other => Err(Error::validation(format!(
+                "invalid signature_type `{other}`; expected one of: 0|1|2"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::error::Validation;
+
+    fn request() -> LimitOrderRequest {
+        LimitOrderRequest::new(U256::from(1), Side::Buy, Decimal::ONE, Decimal::ONE)
+    }
+
+    fn bbo(best_bid: Option<Decimal>, best_ask: Option<Decimal>) -> Bbo {
+        Bbo { best_bid, best_ask }
+    }
+
+    #[test]
+    fn passive_price_should_step_inside_a_better_order() {
+        let bbo = bbo(Some(dec!(0.45)), Some(dec!(0.50)));
+
+        assert_eq!(
+            bbo.passive_price(Side::Buy, dec!(0.43), TickSize::Hundredth, 1),
+            Some(dec!(0.46))
+        );
+        assert_eq!(
+            bbo.passive_price(Side::Buy, dec!(0.43), TickSize::Hundredth, 3),
+            Some(dec!(0.48))
+        );
+        assert_eq!(
+            bbo.passive_price(Side::Sell, dec!(0.52), TickSize::Hundredth, 2),
+            Some(dec!(0.48))
+        );
+    }
+
+    #[test]
+    fn passive_price_should_keep_a_leading_order() {
+        let bbo = bbo(Some(dec!(0.45)), Some(dec!(0.50)));
+
+        assert_eq!(
+            bbo.passive_price(Side::Buy, dec!(0.45), TickSize::Hundredth, 1),
+            Some(dec!(0.45))
+        );
+        assert_eq!(
+            bbo.passive_price(Side::Sell, dec!(0.50), TickSize::Hundredth, 1),
+            Some(dec!(0.50))
+        );
+    }
+
+    #[test]
+    fn passive_price_should_be_none_when_improvement_would_cross() {
+        let locked = bbo(Some(dec!(0.45)), Some(dec!(0.46)));
+        assert_eq!(
+            locked.passive_price(Side::Buy, dec!(0.44), TickSize::Hundredth, 1),
+            None
+        );
+        assert_eq!(
+            locked.passive_price(Side::Sell, dec!(0.47), TickSize::Hundredth, 1),
+            None
+        );
+
+        let wide = bbo(Some(dec!(0.45)), Some(dec!(0.48)));
+        assert_eq!(
+            wide.passive_price(Side::Buy, dec!(0.44), TickSize::Hundredth, 3),
+            None
+        );
+        assert_eq!(
+            wide.passive_price(Side::Buy, dec!(0.44), TickSize::Hundredth, 2),
+            Some(dec!(0.47))
+        );
+    }
+
+    #[test]
+    fn passive_price_should_handle_one_sided_books() {
+        let bids_only = bbo(Some(dec!(0.98)), None);
+        assert_eq!(
+            bids_only.passive_price(Side::Buy, dec!(0.90), TickSize::Hundredth, 1),
+            Some(dec!(0.99))
+        );
+        assert_eq!(
+            bids_only.passive_price(Side::Buy, dec!(0.90), TickSize::Hundredth, 2),
+            None
+        );
+
+        let asks_only = bbo(None, Some(dec!(0.02)));
+        assert_eq!(
+            asks_only.passive_price(Side::Sell, dec!(0.10), TickSize::Hundredth, 1),
+            Some(dec!(0.01))
+        );
+        assert_eq!(
+            asks_only.passive_price(Side::Buy, dec!(0.01), TickSize::Hundredth, 1),
+            Some(dec!(0.01))
+        );
+        assert_eq!(
+            bbo(None, None).passive_price(Side::Unknown, dec!(0.5), TickSize::Hundredth, 1),
+            None
+        );
+    }
+
+    #[test]
+    fn position_should_aggregate_buys_and_sells() {
+        let mut position = Position::new(U256::from(1));
+
+        position.apply_fill(Side::Buy, Decimal::TEN, Decimal::new(40, 2));
+        position.apply_fill(Side::Buy, Decimal::TEN, Decimal::new(50, 2));
+        position.apply_fill(Side::Sell, Decimal::new(5, 0), Decimal::new(60, 2));
+
+        assert_eq!(position.size, Decimal::new(15, 0));
+        assert_eq!(position.avg_price, Some(Decimal::new(45, 2)));
+
+        position.apply_fill(Side::Sell, Decimal::new(15, 0), Decimal::new(60, 2));
+
+        assert_eq!(position.size, Decimal::ZERO);
+        assert_eq!(position.avg_price, None);
+    }
+
+    #[test]
+    fn with_expiration_unix_should_succeed() {
+        let secs = Utc::now().timestamp() + 3_600;
+        let request = request().with_expiration_unix(secs).unwrap();
+
+        assert_eq!(request.order_type, Some(OrderType::GTD));
+        assert_eq!(request.expiration.map(|e| e.timestamp()), Some(secs));
+    }
+
+    #[test]
+    fn with_expiration_unix_non_positive_should_fail() {
+        for secs in [0, -1] {
+            let err = request().with_expiration_unix(secs).unwrap_err();
+            let validation = err.downcast_ref::<Validation>().unwrap();
+            assert_eq!(
+                validation.reason,
+                format!("expiration must be a positive unix timestamp, got {secs}")
+            );
+        }
+    }
+
+    #[test]
+    fn with_expiration_unix_in_the_past_should_fail() {
+        let secs = Utc::now().timestamp() - 60;
+        let err = request().with_expiration_unix(secs).unwrap_err();
+        let validation = err.downcast_ref::<Validation>().unwrap();
+
+        assert_eq!(
+            validation.reason,
+            format!("expiration {secs} must be in the future")
+        );
+    }
+
+    #[test]
+    fn post_only_with_immediate_order_type_should_fail() {
+        for order_type in [OrderType::FOK, OrderType::FAK] {
+            let err = request()
+                .with_post_only(true)
+                .unwrap()
+                .with_order_type(order_type.clone())
+                .unwrap_err();
+            assert_eq!(
+                err.downcast_ref::<Validation>().unwrap().reason,
+                "postOnly is only supported for GTC and GTD orders"
+            );
+
+            let err = request()
+                .with_order_type(order_type)
+                .unwrap()
+                .with_post_only(true)
+                .unwrap_err();
+            assert_eq!(
+                err.downcast_ref::<Validation>().unwrap().reason,
+                "postOnly is only supported for GTC and GTD orders"
+            );
+        }
+    }
+
+    #[test]
+    fn post_only_with_resting_order_type_should_succeed() {
+        for order_type in [OrderType::GTC, OrderType::GTD] {
+            let request = request()
+                .with_order_type(order_type.clone())
+                .unwrap()
+                .with_post_only(true)
+                .unwrap();
+
+            assert_eq!(request.order_type, Some(order_type));
+            assert_eq!(request.post_only, Some(true));
+        }
+
+        let mut request = request().with_order_type(OrderType::FOK).unwrap();
+        request.post_only = Some(true);
+        let err = request.validate().unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "postOnly is only supported for GTC and GTD orders"
+        );
+    }
+
+    #[test]
+    fn signature_type_input_try_from_u8_should_map_each_byte() {
+        for (byte, expected) in [
+            (0, SignatureType::Eoa),
+            (1, SignatureType::Proxy),
+            (2, SignatureType::GnosisSafe),
+        ] {
+            let input = SignatureTypeInput::try_from(byte).unwrap();
+            assert_eq!(SignatureType::from(input), expected);
+            assert_eq!(expected as u8, byte);
+        }
+    }
+
+    #[test]
+    fn signature_type_input_try_from_invalid_byte_should_fail() {
+        let err = SignatureTypeInput::try_from(3).unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "invalid signature_type `3`; expected one of: 0|1|2"
+        );
+    }
+
+    fn parent(size: Decimal) -> LimitOrderRequest {
+        LimitOrderRequest::new(U256::from(1), Side::Buy, Decimal::new(50, 2), size)
+            .with_post_only(true)
+            .unwrap()
+    }
+
+    fn assert_children(children: &[LimitOrderRequest], size: Decimal, lot_size_scale: u32) {
+        let total: Decimal = children.iter().map(|child| child.size).sum();
+        assert_eq!(total, size, "child sizes should sum to the parent size");
+        for child in children {
+            assert!(
+                child.size > Decimal::ZERO,
+                "child size {} should be positive",
+                child.size
+            );
+            assert!(
+                child.size.scale() <= lot_size_scale,
+                "child size {} should respect the lot scale",
+                child.size
+            );
+            assert_eq!(
+                child.post_only,
+                Some(true),
+                "child should keep parent fields"
+            );
+        }
+    }
+
+    #[test]
+    fn split_should_put_remainder_on_last_child() {
+        let size = Decimal::new(1001, 2);
+        let children = parent(size).split(3, 2).unwrap();
+
+        let sizes: Vec<_> = children.iter().map(|child| child.size).collect();
+        assert_eq!(
+            sizes,
+            vec![
+                Decimal::new(333, 2),
+                Decimal::new(333, 2),
+                Decimal::new(335, 2)
+            ]
+        );
+        assert_children(&children, size, 2);
+    }
+
+    #[test]
+    fn split_randomized_should_sum_to_parent_and_respect_lot_scale() {
+        let size = Decimal::new(12_345_678, 4);
+        for _ in 0..100 {
+            let children = parent(size).split_randomized(7, 4).unwrap();
+
+            assert_eq!(children.len(), 7);
+            assert_children(&children, size, 4);
+        }
+    }
+
+    #[test]
+    fn split_should_reject_invalid_inputs() {
+        let err = parent(Decimal::ONE).split(0, 2).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Unable to split order into 0 children"
+        );
+
+        let err = parent(Decimal::new(1001, 3)).split(2, 2).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Unable to split order: Size 1.001 has 3 decimal places. Maximum lot size is 2"
+        );
+
+        let err = parent(Decimal::new(2, 2))
+            .split_randomized(3, 2)
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Unable to split order: size 0.02 is smaller than 3 lots"
+        );
+    }
+}
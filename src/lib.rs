@@ -119,6 +119,8 @@ pub struct ContractConfig {
     pub conditional_tokens: Address,
     /// The Neg Risk Adapter contract address. Only present for neg-risk market configs.
     /// Users must approve this contract for token transfers to trade in neg-risk markets.
+    /// Orders are never signed against the adapter: the EIP-712 verifying contract is always
+    /// [`Self::exchange`].
     pub neg_risk_adapter: Option<Address>,
 }
 
@@ -198,6 +200,29 @@ pub fn derive_safe_wallet(eoa_address: Address, chain_id: ChainId) -> Option<Add
     Some(factory.create2(salt, SAFE_INIT_CODE_HASH))
 }
 
+/// Reads every variable in `names` through `lookup`, naming all missing or empty ones in the
+/// error.
+pub(crate) fn required_vars<const N: usize, F: Fn(&str) -> Option<String>>(
+    names: [&str; N],
+    lookup: F,
+) -> Result<[String; N]> {
+    let values = names.map(|name| lookup(name).filter(|value| !value.is_empty()));
+    let missing: Vec<&str> = names
+        .iter()
+        .zip(&values)
+        .filter(|(_, value)| value.is_none())
+        .map(|(name, _)| *name)
+        .collect();
+    if !missing.is_empty() {
+        return Err(Error::validation(format!(
+            "missing environment variables: {}",
+            missing.join(", ")
+        )));
+    }
+
+    Ok(values.map(Option::unwrap_or_default))
+}
+
 /// Trait for converting request types to URL query parameters.
 ///
 /// This trait is automatically implemented for all types that implement [`Serialize`].
@@ -306,8 +331,19 @@ async fn request<Response: DeserializeOwned>(
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use super::*;
 
+    /// Environment lookup over `vars`, for testing the `from_lookup` constructors.
+    pub(crate) fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|&(name, value)| (name.to_owned(), value.to_owned()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
     #[test]
     fn config_contains_80002() {
         let cfg = contract_config(AMOY, false).expect("missing config");
@@ -2,6 +2,7 @@
 //!
 //! These types are commonly used in this SDK and are re-exported here
 //! so users don't need to add these dependencies to their `Cargo.toml`.
+//! Helpers for converting token ids between their string forms live here too.
 
 /// Ethereum address type and the [`address!`] macro for compile-time address literals.
 /// [`ChainId`] is a type alias for `u64` representing EVM chain IDs.
@@ -21,3 +22,96 @@ pub use rust_decimal::Decimal;
 /// let price = dec!(0.55);
 /// ```
 pub use rust_decimal_macros::dec;
+
+use crate::Result;
+use crate::error::Error;
+
+/// Parses a token id given either as `0x`-prefixed hex or as a decimal string.
+///
+/// The CLOB reports token ids in decimal, while on-chain tooling tends to print them in hex;
+/// the prefix decides which is meant, so a hex id is never misread as decimal.
+///
+/// # Errors
+///
+/// Returns a validation error when `value` is empty, contains digits invalid for its base,
+/// or overflows 256 bits.
+pub fn token_id_from_str(value: &str) -> Result<U256> {
+    let (digits, radix) = match value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        Some(hex) => (hex, 16),
+        None => (value, 10),
+    };
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err(Error::validation(format!("invalid token id: {value:?}")));
+    }
+
+    U256::from_str_radix(digits, u64::from(radix))
+        .map_err(|e| Error::validation(format!("invalid token id {value:?}: {e}")))
+}
+
+/// Formats `token_id` as `0x`-prefixed lowercase hex.
+#[must_use]
+pub fn token_id_to_hex(token_id: U256) -> String {
+    format!("0x{token_id:x}")
+}
+
+/// Formats `token_id` in decimal, the form the CLOB uses.
+#[must_use]
+pub fn token_id_to_decimal(token_id: U256) -> String {
+    token_id.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Kind;
+
+    const DECIMAL: &str =
+        "71321045679252212594626385532706912750332728571942532289631379312455583992563";
+
+    #[test]
+    fn token_id_should_round_trip_decimal_and_hex() {
+        let token_id = token_id_from_str(DECIMAL).unwrap();
+
+        assert_eq!(token_id_to_decimal(token_id), DECIMAL);
+
+        let hex = token_id_to_hex(token_id);
+        assert!(hex.starts_with("0x"), "hex should be prefixed: {hex}");
+        assert_eq!(token_id_from_str(&hex).unwrap(), token_id);
+        assert_eq!(
+            token_id_from_str(&hex.to_uppercase().replacen("0X", "0x", 1)).unwrap(),
+            token_id
+        );
+    }
+
+    #[test]
+    fn token_id_from_str_should_not_read_hex_as_decimal() {
+        assert_eq!(token_id_from_str("0x10").unwrap(), U256::from(16));
+        assert_eq!(token_id_from_str("10").unwrap(), U256::from(10));
+        assert_eq!(token_id_to_hex(U256::ZERO), "0x0");
+    }
+
+    #[test]
+    fn token_id_from_str_should_reject_malformed_input() {
+        for value in [
+            "",
+            "0x",
+            "12ab",
+            "0xzz",
+            "-1",
+            " 1",
+            "1_000",
+            format!("{DECIMAL}0").as_str(),
+        ] {
+            let err = token_id_from_str(value).unwrap_err();
+            assert_eq!(
+                err.kind(),
+                Kind::Validation,
+                "unexpected error for {value:?}"
+            );
+        }
+    }
+}
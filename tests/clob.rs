@@ -1389,7 +1389,7 @@ mod authenticated {
     };
     use polymarket_client_sdk::clob::types::response::{
         ApiKeysResponse, BalanceAllowanceResponse, BanStatusResponse, CancelOrdersResponse,
-        CurrentRewardResponse, Earning, HeartbeatResponse, MakerOrder, MarketRewardResponse,
+        CurrentRewardResponse, Earning, Fill, HeartbeatResponse, MakerOrder, MarketRewardResponse,
         MarketRewardsConfig, NotificationPayload, NotificationResponse, OpenOrderResponse,
         OrderScoringResponse, Page, PostOrderResponse, RewardsConfig, Token,
         TotalUserEarningResponse, TradeResponse, UserEarningResponse, UserRewardsEarningResponse,
@@ -1674,6 +1674,85 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn post_order_crossing_should_parse_fills() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "5",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "matched",
+                "success": true,
+                "takingAmount": "10",
+                "fills": [
+                    { "price": "0.49", "size": "6", "fee": "0.01" },
+                    { "price": "0.5", "size": "4", "fee": "0" }
+                ]
+            }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let response = client.post_order(signed_order).await?;
+
+        assert_eq!(
+            response.fills,
+            vec![
+                Fill::builder()
+                    .price(dec!(0.49))
+                    .size(dec!(6))
+                    .fee(dec!(0.01))
+                    .build(),
+                Fill::builder()
+                    .price(dec!(0.5))
+                    .size(dec!(4))
+                    .fee(Decimal::ZERO)
+                    .build(),
+            ]
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_order_resting_should_have_no_fills() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let response = client.post_order(signed_order).await?;
+
+        assert!(
+            response.fills.is_empty(),
+            "resting order should have no fills"
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn order_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
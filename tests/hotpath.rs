@@ -0,0 +1,3085 @@
+#![cfg(feature = "clob")]
+#![allow(
+    clippy::unwrap_used,
+    reason = "Do not need additional syntax for setting up tests, and https://github.com/rust-lang/rust-clippy/issues/13981"
+)]
+
+mod common;
+
+use httpmock::MockServer;
+use polymarket_client_sdk::POLYGON;
+use polymarket_client_sdk::auth::{Credentials, SecretString};
+use polymarket_client_sdk::clob::types::{SignatureType, TickSize};
+use polymarket_client_sdk::hotpath::{AuthLayer, HotPathClient, HotPathConfig, HotPathPolicies};
+use polymarket_client_sdk::types::{Address, address};
+use reqwest::StatusCode;
+use serde_json::json;
+use url::Url;
+
+use crate::common::{API_KEY, PASSPHRASE, PRIVATE_KEY, SECRET, token_1, token_2};
+
+const FUNDER: Address = address!("0x995c9b1f779c04e65ac6ab2fd8a6e5e2e8e6a7f1");
+
+fn fixed_policies() -> HotPathPolicies {
+    HotPathPolicies::fixed(TickSize::Hundredth, false, 0)
+}
+
+fn create_client(server: &MockServer) -> anyhow::Result<HotPathClient> {
+    create_client_at(&server.base_url())
+}
+
+fn create_client_at(host: &str) -> anyhow::Result<HotPathClient> {
+    create_client_with(host, |config| config)
+}
+
+/// Like [`create_client_at`], with `configure` applied to the config first.
+fn create_client_with<F: FnOnce(HotPathConfig) -> HotPathConfig>(
+    host: &str,
+    configure: F,
+) -> anyhow::Result<HotPathClient> {
+    let config = HotPathConfig::new(
+        Url::parse(host)?,
+        POLYGON,
+        SecretString::from(PRIVATE_KEY.to_owned()),
+        SignatureType::Proxy,
+        FUNDER,
+        None,
+        fixed_policies(),
+    )?
+    .with_allow_insecure(true);
+    let credentials = Credentials::new(API_KEY, SECRET.to_owned(), PASSPHRASE.to_owned());
+
+    Ok(HotPathClient::with_credentials(
+        configure(config),
+        credentials,
+    )?)
+}
+
+mod market_data {
+    use polymarket_client_sdk::clob::types::Side;
+    use polymarket_client_sdk::error::Kind;
+    use std::time::Duration;
+
+    use polymarket_client_sdk::hotpath::{
+        Bbo, Http2Settings, LimitOrderOverrides, LimitOrderRequest, RetryPolicy,
+    };
+    use polymarket_client_sdk::types::U256;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn empty_book() -> serde_json::Value {
+        json!({
+            "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "asset_id": token_1().to_string(),
+            "timestamp": "1764612536000",
+            "bids": [],
+            "asks": [],
+            "min_order_size": "5",
+            "neg_risk": false,
+            "tick_size": "0.01"
+        })
+    }
+
+    fn market(tokens: &serde_json::Value) -> serde_json::Value {
+        json!({
+            "enable_order_book": true,
+            "active": true,
+            "closed": false,
+            "archived": false,
+            "accepting_orders": true,
+            "accepting_order_timestamp": "2024-01-15T12:34:56Z",
+            "minimum_order_size": "5",
+            "minimum_tick_size": "0.01",
+            "condition_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "question_id": "",
+            "question": "Will BTC close above $50k today?",
+            "description": "A market about BTC daily close price",
+            "market_slug": "btc-close-above-50k",
+            "end_date_iso": null,
+            "game_start_time": null,
+            "seconds_delay": 0,
+            "fpmm": "",
+            "maker_base_fee": "0",
+            "taker_base_fee": "0",
+            "notifications_enabled": true,
+            "neg_risk": false,
+            "neg_risk_market_id": "",
+            "neg_risk_request_id": "",
+            "icon": "",
+            "image": "",
+            "rewards": { "rates": null, "min_size": "0", "max_spread": "0" },
+            "is_50_50_outcome": false,
+            "tokens": tokens,
+            "tags": []
+        })
+    }
+
+    #[tokio::test]
+    async fn token_ids_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let tokens = json!([
+            { "token_id": token_2(), "outcome": "No", "price": "0.45" },
+            { "token_id": token_1(), "outcome": "Yes", "price": "0.55" }
+        ]);
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/markets/0x01");
+            then.status(StatusCode::OK).json_body(market(&tokens));
+        });
+
+        assert_eq!(client.token_ids("0x01").await?, [token_1(), token_2()]);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn token_ids_without_two_tokens_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let tokens = json!([{ "token_id": token_1(), "outcome": "Yes", "price": "0.55" }]);
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/markets/0x01");
+            then.status(StatusCode::OK).json_body(market(&tokens));
+        });
+
+        let err = client.token_ids("0x01").await.unwrap_err();
+        assert_eq!(err.kind(), Kind::Validation);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_sampling_markets_should_paginate() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let tokens = json!([
+            { "token_id": token_1(), "outcome": "Yes", "price": "0.55" },
+            { "token_id": token_2(), "outcome": "No", "price": "0.45" }
+        ]);
+        let first = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/sampling-markets")
+                .query_param_missing("next_cursor");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [market(&tokens)],
+                "next_cursor": "MQ==",
+                "limit": 1,
+                "count": 1
+            }));
+        });
+        let second = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/sampling-markets")
+                .query_param("next_cursor", "MQ==");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [market(&tokens)],
+                "next_cursor": "LTE=",
+                "limit": 1,
+                "count": 1
+            }));
+        });
+
+        let page = client.get_sampling_markets(None).await?;
+        assert_eq!(page.data.len(), 1);
+        assert_eq!(page.next_cursor, "MQ==");
+
+        let page = client.get_sampling_markets(Some(page.next_cursor)).await?;
+        assert_eq!(page.data.len(), 1);
+        assert_eq!(page.next_cursor, "LTE=");
+
+        first.assert();
+        second.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_midpoint_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/midpoint")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK)
+                .json_body(json!({ "mid": "0.45" }));
+        });
+
+        assert_eq!(client.get_midpoint(token_1()).await?, Some(dec!(0.45)));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn client_with_http2_settings_should_reach_http1_hosts() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let settings = Http2Settings::new(
+            Some(Duration::from_secs(1)),
+            Duration::from_millis(500),
+            false,
+        );
+        let client = create_client_with(&server.base_url(), |config| {
+            assert_ne!(
+                config.http2, settings,
+                "settings should differ from the defaults"
+            );
+            config.with_http2(settings)
+        })?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/midpoint");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "mid": "0.45" }));
+        });
+
+        assert_eq!(client.get_midpoint(token_1()).await?, Some(dec!(0.45)));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_last_trade_price_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/last-trade-price")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK)
+                .json_body(json!({ "price": "0.47", "side": "BUY" }));
+        });
+
+        assert_eq!(
+            client.get_last_trade_price(token_1()).await?,
+            Some(dec!(0.47))
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_midpoint_with_empty_mid_should_return_none() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/midpoint");
+            then.status(StatusCode::OK).json_body(json!({ "mid": "" }));
+        });
+
+        assert_eq!(client.get_midpoint(token_1()).await?, None);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_midpoint_without_orderbook_should_return_none() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/midpoint");
+            then.status(StatusCode::NOT_FOUND).json_body(json!({
+                "error": "No orderbook exists for the requested token id"
+            }));
+        });
+
+        assert_eq!(client.get_midpoint(token_1()).await?, None);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_midpoint_should_retry_on_service_unavailable() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client_with(&server.base_url(), |config| {
+            config.with_read_retry(RetryPolicy::new(2, Duration::ZERO, Duration::ZERO))
+        })?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/midpoint");
+            then.status(StatusCode::SERVICE_UNAVAILABLE)
+                .json_body(json!({ "error": "service unavailable" }));
+        });
+
+        let err = client.get_midpoint(token_1()).await.unwrap_err();
+
+        assert_eq!(err.status_code(), Some(503));
+        mock.assert_calls(3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_price_with_empty_book_should_return_none() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/price")
+                .query_param("token_id", token_1().to_string())
+                .query_param("side", "BUY");
+            then.status(StatusCode::OK).json_body(json!({}));
+        });
+
+        assert_eq!(client.get_price(token_1(), Side::Buy).await?, None);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_price_without_orderbook_should_return_none() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/price");
+            then.status(StatusCode::NOT_FOUND).json_body(json!({
+                "error": "No orderbook exists for the requested token id"
+            }));
+        });
+
+        assert_eq!(client.get_price(token_1(), Side::Sell).await?, None);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn is_market_active_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let tokens = json!([
+            { "token_id": token_1(), "outcome": "Yes", "price": "0.55" },
+            { "token_id": token_2(), "outcome": "No", "price": "0.45" }
+        ]);
+        let book = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK).json_body(empty_book());
+        });
+        let market_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path(
+                "/markets/0x0000000000000000000000000000000000000000000000000000000000000001",
+            );
+            then.status(StatusCode::OK).json_body(market(&tokens));
+        });
+
+        assert!(
+            client.is_market_active(token_1()).await?,
+            "market should be active"
+        );
+        book.assert();
+        market_mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sign_limit_order_should_enforce_market_order_size_limits() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client_with(&server.base_url(), |config| {
+            config.with_market_order_size_limits(true)
+        })?;
+
+        let tokens = json!([
+            { "token_id": token_1(), "outcome": "Yes", "price": "0.55" },
+            { "token_id": token_2(), "outcome": "No", "price": "0.45" }
+        ]);
+        let mut bounded = market(&tokens);
+        bounded["minimum_order_size"] = json!("15");
+        bounded["maximum_order_size"] = json!("1000");
+        let book = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK).json_body(empty_book());
+        });
+        let market_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path(
+                "/markets/0x0000000000000000000000000000000000000000000000000000000000000001",
+            );
+            then.status(StatusCode::OK).json_body(bounded);
+        });
+
+        let sign = |token_id, size| {
+            let request = LimitOrderRequest::new(token_id, Side::Buy, dec!(0.50), size);
+            let client = &client;
+            async move {
+                client
+                    .sign_limit_order(&request, LimitOrderOverrides::default())
+                    .await
+            }
+        };
+
+        sign(token_1(), dec!(15)).await?;
+        sign(token_2(), dec!(1000)).await?;
+        let below = sign(token_1(), dec!(14.99)).await.unwrap_err();
+        let above = sign(token_2(), dec!(1000.01)).await.unwrap_err();
+
+        assert_eq!(below.kind(), Kind::Validation);
+        assert!(
+            below
+                .to_string()
+                .contains("below the market's minimum order size 15"),
+            "unexpected error: {below}"
+        );
+        assert!(
+            above
+                .to_string()
+                .contains("exceeds the market's maximum order size 1000"),
+            "unexpected error: {above}"
+        );
+
+        // The cached bounds also apply where nothing can be fetched.
+        let too_small = LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.50), dec!(14.99));
+        let validated = client
+            .validate_order(&too_small, LimitOrderOverrides::default())
+            .unwrap_err();
+        let blocking = client
+            .sign_limit_order_blocking(&too_small, LimitOrderOverrides::default())
+            .unwrap_err();
+        assert_eq!(validated.to_string(), below.to_string());
+        assert_eq!(blocking.to_string(), below.to_string());
+        book.assert_calls(1);
+        market_mock.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn order_size_limits_should_default_to_no_maximum() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let tokens = json!([
+            { "token_id": token_1(), "outcome": "Yes", "price": "0.55" },
+            { "token_id": token_2(), "outcome": "No", "price": "0.45" }
+        ]);
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/book");
+            then.status(StatusCode::OK).json_body(empty_book());
+        });
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path(
+                "/markets/0x0000000000000000000000000000000000000000000000000000000000000001",
+            );
+            then.status(StatusCode::OK).json_body(market(&tokens));
+        });
+
+        let limits = client.order_size_limits(token_1()).await?;
+
+        assert_eq!(limits.min, dec!(5));
+        assert_eq!(limits.max, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn is_market_active_with_closed_market_should_return_false() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let tokens = json!([
+            { "token_id": token_1(), "outcome": "Yes", "price": "1" },
+            { "token_id": token_2(), "outcome": "No", "price": "0" }
+        ]);
+        let mut closed = market(&tokens);
+        closed["closed"] = json!(true);
+        closed["accepting_orders"] = json!(false);
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/book");
+            then.status(StatusCode::OK).json_body(empty_book());
+        });
+        let market_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path(
+                "/markets/0x0000000000000000000000000000000000000000000000000000000000000001",
+            );
+            then.status(StatusCode::OK).json_body(closed);
+        });
+
+        assert!(
+            !client.is_market_active(token_1()).await?,
+            "market should be closed"
+        );
+        market_mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn is_market_active_without_book_should_return_false() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/book");
+            then.status(StatusCode::NOT_FOUND)
+                .json_body(json!({ "error": "No orderbook exists for the requested token id" }));
+        });
+
+        assert!(
+            !client.is_market_active(token_1()).await?,
+            "market should be inactive"
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_bbo_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mut book = empty_book();
+        book["bids"] = json!([
+            { "price": "0.40", "size": "10" },
+            { "price": "0.42", "size": "5" }
+        ]);
+        book["asks"] = json!([
+            { "price": "0.47", "size": "3" },
+            { "price": "0.45", "size": "8" }
+        ]);
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK).json_body(book);
+        });
+
+        let bbo = client.get_bbo(token_1()).await?;
+
+        assert_eq!(bbo.best_bid, Some(dec!(0.42)));
+        assert_eq!(bbo.best_ask, Some(dec!(0.45)));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_order_book_with_depth_should_keep_top_levels() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mut book = empty_book();
+        book["bids"] = json!([
+            { "price": "0.40", "size": "10" },
+            { "price": "0.41", "size": "7" },
+            { "price": "0.42", "size": "5" }
+        ]);
+        book["asks"] = json!([
+            { "price": "0.47", "size": "3" },
+            { "price": "0.46", "size": "4" },
+            { "price": "0.45", "size": "8" }
+        ]);
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK).json_body(book);
+        });
+
+        let book = client.get_order_book(token_1(), Some(2)).await?.unwrap();
+
+        let bids: Vec<_> = book.bids.iter().map(|level| level.price).collect();
+        let asks: Vec<_> = book.asks.iter().map(|level| level.price).collect();
+        assert_eq!(bids, [dec!(0.42), dec!(0.41)]);
+        assert_eq!(asks, [dec!(0.45), dec!(0.46)]);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_bbos_should_collect_each_token() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+        let token_3 = token_2() + U256::from(1);
+        let token_4 = token_2() + U256::from(2);
+
+        let mut book = empty_book();
+        book["bids"] = json!([{ "price": "0.40", "size": "10" }]);
+        book["asks"] = json!([{ "price": "0.45", "size": "8" }]);
+        let quoted = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK).json_body(book);
+        });
+        let empty = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", token_2().to_string());
+            then.status(StatusCode::OK).json_body(empty_book());
+        });
+        let missing = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", token_3.to_string());
+            then.status(StatusCode::NOT_FOUND)
+                .json_body(json!({ "error": "No orderbook exists for the requested token id" }));
+        });
+        let failing = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", token_4.to_string());
+            then.status(StatusCode::INTERNAL_SERVER_ERROR)
+                .json_body(json!({ "error": "internal error" }));
+        });
+
+        let bbos = client
+            .get_bbos(&[token_1(), token_2(), token_3, token_4])
+            .await;
+
+        assert_eq!(bbos.len(), 4);
+        let quoted_bbo = bbos[&token_1()].as_ref().unwrap();
+        assert_eq!(quoted_bbo.best_bid, Some(dec!(0.40)));
+        assert_eq!(quoted_bbo.best_ask, Some(dec!(0.45)));
+        assert_eq!(*bbos[&token_2()].as_ref().unwrap(), Bbo::default());
+        assert_eq!(*bbos[&token_3].as_ref().unwrap(), Bbo::default());
+        let err = bbos[&token_4].as_ref().unwrap_err();
+        assert_eq!(err.status_code(), Some(500));
+        quoted.assert();
+        empty.assert();
+        missing.assert();
+        failing.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_prices_should_batch_queries_in_one_request() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/prices")
+                .json_body(json!([
+                    { "token_id": token_1().to_string(), "side": "BUY" },
+                    { "token_id": token_1().to_string(), "side": "SELL" },
+                    { "token_id": token_2().to_string(), "side": "BUY" }
+                ]));
+            then.status(StatusCode::OK).json_body(json!({
+                token_1().to_string(): { "BUY": "0.48", "SELL": "0.52" }
+            }));
+        });
+
+        let prices = client
+            .get_prices(&[
+                (token_1(), Side::Buy),
+                (token_1(), Side::Sell),
+                (token_2(), Side::Buy),
+            ])
+            .await?;
+
+        assert_eq!(prices.len(), 2);
+        assert_eq!(prices[&(token_1(), Side::Buy)], dec!(0.48));
+        assert_eq!(prices[&(token_1(), Side::Sell)], dec!(0.52));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_bbo_with_empty_book_should_return_empty_sides() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/book");
+            then.status(StatusCode::OK).json_body(empty_book());
+        });
+
+        assert_eq!(client.get_bbo(token_1()).await?, Bbo::default());
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_bbo_without_orderbook_should_return_empty_sides() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/book");
+            then.status(StatusCode::NOT_FOUND).json_body(json!({
+                "error": "No orderbook exists for the requested token id"
+            }));
+        });
+
+        assert_eq!(client.get_bbo(token_1()).await?, Bbo::default());
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_midpoint_with_server_error_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/midpoint");
+            then.status(StatusCode::INTERNAL_SERVER_ERROR);
+        });
+
+        client.get_midpoint(token_1()).await.unwrap_err();
+        mock.assert();
+
+        Ok(())
+    }
+}
+
+mod pool {
+    use std::io::{BufRead as _, BufReader, Read as _, Write as _};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
+
+    use polymarket_client_sdk::auth::Uuid;
+    use polymarket_client_sdk::clob::types::Side;
+    use polymarket_client_sdk::hotpath::{LimitOrderRequest, RetryPolicy};
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    /// Serves `{"mid": "0.5"}` over keep-alive HTTP/1.1 and counts accepted connections.
+    fn spawn_keep_alive_server() -> anyhow::Result<(String, Arc<AtomicUsize>)> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let host = format!("http://{}", listener.local_addr()?);
+        let connections = Arc::new(AtomicUsize::new(0));
+
+        let accepted = Arc::clone(&connections);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                accepted.fetch_add(1, Ordering::SeqCst);
+
+                thread::spawn(move || {
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    loop {
+                        let mut line = String::new();
+                        // Drain one request head; GETs carry no body.
+                        loop {
+                            line.clear();
+                            if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                                return;
+                            }
+                            if line == "\r\n" {
+                                break;
+                            }
+                        }
+
+                        let body = r#"{"mid":"0.5"}"#;
+                        let response = format!(
+                            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+                            body.len()
+                        );
+                        if stream.write_all(response.as_bytes()).is_err() {
+                            return;
+                        }
+                    }
+                });
+            }
+        });
+
+        Ok((host, connections))
+    }
+
+    /// Records every `POST /order` body and answers each with the same live order, except
+    /// the first, which is held open for `stall` so the client times out.
+    fn spawn_flaky_order_server(
+        stall: Duration,
+    ) -> anyhow::Result<(String, Arc<Mutex<Vec<String>>>)> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let host = format!("http://{}", listener.local_addr()?);
+        let bodies = Arc::new(Mutex::new(Vec::new()));
+
+        let received = Arc::clone(&bodies);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                let received = Arc::clone(&received);
+
+                thread::spawn(move || {
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let mut content_length = 0;
+                    let mut line = String::new();
+                    loop {
+                        line.clear();
+                        if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                            return;
+                        }
+                        if line == "\r\n" {
+                            break;
+                        }
+                        if let Some((name, value)) = line.split_once(':')
+                            && name.eq_ignore_ascii_case("content-length")
+                        {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                    let mut body = vec![0; content_length];
+                    reader.read_exact(&mut body).unwrap();
+
+                    let attempt = {
+                        let mut received = received.lock().unwrap();
+                        received.push(String::from_utf8(body).unwrap());
+                        received.len()
+                    };
+                    if attempt == 1 {
+                        thread::sleep(stall);
+                        return;
+                    }
+
+                    let body = r#"{"error_msg":"","makingAmount":"","orderID":"0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0","status":"live","success":true,"takingAmount":""}"#;
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                    _ = stream.write_all(response.as_bytes());
+                });
+            }
+        });
+
+        Ok((host, bodies))
+    }
+
+    #[tokio::test]
+    async fn clones_should_share_connection_pool() -> anyhow::Result<()> {
+        let (host, connections) = spawn_keep_alive_server()?;
+        let client = create_client_at(&host)?;
+        let clone = client.clone();
+
+        assert_eq!(client.get_midpoint(token_1()).await?, Some(dec!(0.5)));
+        assert_eq!(clone.get_midpoint(token_1()).await?, Some(dec!(0.5)));
+        assert_eq!(client.get_midpoint(token_1()).await?, Some(dec!(0.5)));
+
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn clones_should_hold_independent_credentials() -> anyhow::Result<()> {
+        let (host, _) = spawn_keep_alive_server()?;
+        let client = create_client_at(&host)?;
+
+        let other = Credentials::new(Uuid::max(), SECRET.to_owned(), PASSPHRASE.to_owned());
+        let mut clone = client.clone();
+        clone.set_credentials(other);
+
+        assert_eq!(client.credentials().key(), API_KEY);
+        assert_eq!(clone.credentials().key(), Uuid::max());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_limit_order_reliable_should_resend_identical_order_after_timeout()
+    -> anyhow::Result<()> {
+        let (host, bodies) = spawn_flaky_order_server(Duration::from_secs(2))?;
+        let config = HotPathConfig::new(
+            Url::parse(&host)?,
+            POLYGON,
+            SecretString::from(PRIVATE_KEY.to_owned()),
+            SignatureType::Proxy,
+            FUNDER,
+            None,
+            fixed_policies(),
+        )?
+        .with_allow_insecure(true)
+        .with_write_retry(RetryPolicy::new(2, Duration::ZERO, Duration::ZERO));
+        let credentials = Credentials::new(API_KEY, SECRET.to_owned(), PASSPHRASE.to_owned());
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_millis(200))
+            .build()?;
+        let client = HotPathClient::with_credentials_and_client(config, credentials, http)?;
+
+        let request = LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.45), dec!(10));
+        let response = client.post_limit_order_reliable(&request).await?;
+
+        assert!(response.success, "retried order should be accepted");
+        let bodies = bodies.lock().unwrap().clone();
+        assert_eq!(bodies.len(), 2, "timed out attempt should be retried once");
+        assert_eq!(
+            bodies[0], bodies[1],
+            "retry should resend the identical order"
+        );
+
+        Ok(())
+    }
+}
+
+mod account {
+    use std::num::NonZeroUsize;
+    use std::str::FromStr as _;
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    use alloy::signers::Signer as _;
+    use alloy::signers::local::LocalSigner;
+    use futures_util::StreamExt as _;
+    use futures_util::future::join_all;
+    use polymarket_client_sdk::auth::Uuid;
+    use polymarket_client_sdk::clob::types::request::TradesRequest;
+    use polymarket_client_sdk::clob::types::response::OpenOrderResponse;
+    use polymarket_client_sdk::clob::types::{
+        Order, OrderStatusType, OrderType, Side, SignedOrder,
+    };
+    use polymarket_client_sdk::error::{CancelRejected, FieldValidation, Kind};
+    use polymarket_client_sdk::hotpath::{
+        CredentialMode, CredentialsOrigin, ExportFormat, LimitOrderOverrides, LimitOrderRequest,
+        Position, RetryPolicy, TimePolicy,
+    };
+    use polymarket_client_sdk::types::{U256, b256};
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::common::{
+        POLY_ADDRESS, POLY_API_KEY, POLY_NONCE, POLY_PASSPHRASE, POLY_SIGNATURE, POLY_TIMESTAMP,
+        TIMESTAMP,
+    };
+
+    const CONDITION_ID: &str = "0x000000000000000000000000000000000000000000000000000000c0dabc0123";
+
+    #[tokio::test]
+    async fn get_rewards_should_parse_current_programs() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/rewards/markets/current")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY.to_string())
+                .header(POLY_PASSPHRASE, PASSPHRASE);
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [
+                    {
+                        "condition_id": CONDITION_ID,
+                        "rewards_max_spread": "3.5",
+                        "rewards_min_size": "50",
+                        "rewards_config": [
+                            {
+                                "asset_address": "0x0000000000000000000000000000000000000001",
+                                "start_date": "2024-01-01",
+                                "end_date": "2024-12-31",
+                                "rate_per_day": "2.0",
+                                "total_rewards": "750.0"
+                            },
+                            {
+                                "asset_address": "0x0000000000000000000000000000000000000002",
+                                "start_date": "2024-06-01",
+                                "end_date": "2024-12-31",
+                                "rate_per_day": "1.5",
+                                "total_rewards": "300.0"
+                            }
+                        ]
+                    }
+                ],
+                "limit": 1,
+                "count": 1,
+                "next_cursor": "LTE="
+            }));
+        });
+
+        let rewards = client.get_rewards(None).await?;
+
+        assert_eq!(rewards.len(), 1);
+        assert_eq!(
+            rewards[0].condition_id,
+            b256!("000000000000000000000000000000000000000000000000000000c0dabc0123")
+        );
+        assert_eq!(rewards[0].max_spread, dec!(3.5));
+        assert_eq!(rewards[0].min_size, dec!(50));
+        assert_eq!(rewards[0].daily_rate, dec!(3.5));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_rewards_for_market_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path(format!("/rewards/markets/{CONDITION_ID}"))
+                .header(POLY_API_KEY, API_KEY.to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [
+                    {
+                        "condition_id": CONDITION_ID,
+                        "question": "Will BTC reach $100k in 2025?",
+                        "market_slug": "btc-100k-2025",
+                        "event_slug": "btc-2025",
+                        "image": "https://example.com/markets/btc.png",
+                        "rewards_max_spread": "3",
+                        "rewards_min_size": "20",
+                        "market_competitiveness": 0.05,
+                        "tokens": [],
+                        "rewards_config": [
+                            {
+                                "id": 1,
+                                "asset_address": "0x0000000000000000000000000000000000000001",
+                                "start_date": "2024-01-01",
+                                "end_date": "2024-12-31",
+                                "rate_per_day": "1.25",
+                                "total_rewards": "400.0",
+                                "total_days": 10
+                            }
+                        ]
+                    }
+                ],
+                "limit": 1,
+                "count": 1,
+                "next_cursor": "LTE="
+            }));
+        });
+
+        let rewards = client.get_rewards(Some(CONDITION_ID)).await?;
+
+        assert_eq!(rewards.len(), 1);
+        assert_eq!(rewards[0].min_size, dec!(20));
+        assert_eq!(rewards[0].daily_rate, dec!(1.25));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_fee_rate_bps_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/fee-rate")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY.to_string())
+                .header(POLY_PASSPHRASE, PASSPHRASE);
+            then.status(StatusCode::OK)
+                .json_body(json!({ "base_fee": 15 }));
+        });
+
+        assert_eq!(client.get_fee_rate_bps().await?, 15);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_nonce_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/nonce")
+                .header(POLY_API_KEY, API_KEY.to_string());
+            then.status(StatusCode::OK).json_body(json!({ "nonce": 7 }));
+        });
+
+        assert_eq!(client.get_nonce().await?, 7);
+        mock.assert();
+
+        Ok(())
+    }
+
+    fn open_order(status: &str, size_matched: &str) -> serde_json::Value {
+        json!({
+            "id": "1",
+            "status": status,
+            "owner": API_KEY.to_string(),
+            "maker_address": FUNDER,
+            "market": "0x000000000000000000000000000000000000000000000000006d61726b657461",
+            "asset_id": token_1(),
+            "side": "BUY",
+            "original_size": "10",
+            "size_matched": size_matched,
+            "price": "0.45",
+            "associate_trades": [],
+            "outcome": "YES",
+            "created_at": 1_705_322_096,
+            "expiration": "0",
+            "order_type": "GTC"
+        })
+    }
+
+    #[tokio::test]
+    async fn watch_order_should_yield_until_filled() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mut stream = Box::pin(client.watch_order("1", futures_util::stream::repeat(())));
+
+        let mut partial = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/data/order/1")
+                .header(POLY_API_KEY, API_KEY.to_string());
+            then.status(StatusCode::OK)
+                .json_body(open_order("LIVE", "4"));
+        });
+
+        let status = stream.next().await.unwrap()?;
+        assert_eq!(status.status, OrderStatusType::Live);
+        assert_eq!(status.remaining_size(), dec!(6));
+        assert!(
+            !status.is_terminal(),
+            "partial fill should keep the order live"
+        );
+        partial.assert();
+        partial.delete();
+
+        let filled = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/data/order/1");
+            then.status(StatusCode::OK)
+                .json_body(open_order("MATCHED", "10"));
+        });
+
+        let status = stream.next().await.unwrap()?;
+        assert_eq!(status.status, OrderStatusType::Matched);
+        assert!(status.is_filled(), "order should be fully filled");
+        filled.assert();
+
+        assert!(
+            stream.next().await.is_none(),
+            "stream should end once filled"
+        );
+
+        Ok(())
+    }
+
+    fn trade(
+        id: &str,
+        trader_side: &str,
+        side: &str,
+        size: &str,
+        price: &str,
+    ) -> serde_json::Value {
+        json!({
+            "id": id,
+            "taker_order_id": "taker",
+            "market": "0x000000000000000000000000000000000000000000000000006d61726b657461",
+            "asset_id": token_1(),
+            "side": side,
+            "size": size,
+            "fee_rate_bps": "0",
+            "price": price,
+            "status": "CONFIRMED",
+            "match_time": format!("17000000{id}"),
+            "last_update": format!("17000000{id}"),
+            "outcome": "YES",
+            "bucket_index": 0,
+            "owner": API_KEY.to_string(),
+            "maker_address": FUNDER,
+            "maker_orders": [],
+            "transaction_hash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+            "trader_side": trader_side
+        })
+    }
+
+    #[tokio::test]
+    async fn position_should_aggregate_fills() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mut maker_fill = trade("02", "MAKER", "SELL", "10", "0.50");
+        maker_fill["owner"] = json!(Uuid::max().to_string());
+        maker_fill["maker_orders"] = json!([{
+            "order_id": "maker",
+            "owner": API_KEY.to_string(),
+            "maker_address": FUNDER,
+            "matched_amount": "10",
+            "price": "0.50",
+            "fee_rate_bps": "0",
+            "asset_id": token_1(),
+            "outcome": "YES",
+            "side": "BUY"
+        }]);
+
+        let first_page = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/data/trades")
+                .query_param("asset_id", token_1().to_string())
+                .query_param_missing("next_cursor")
+                .header(POLY_API_KEY, API_KEY.to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [trade("03", "TAKER", "SELL", "5", "0.60"), maker_fill],
+                "next_cursor": "MQ==",
+                "limit": 2,
+                "count": 2
+            }));
+        });
+        let second_page = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/data/trades")
+                .query_param("next_cursor", "MQ==");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [trade("01", "TAKER", "BUY", "10", "0.40")],
+                "next_cursor": "LTE=",
+                "limit": 2,
+                "count": 1
+            }));
+        });
+
+        let position = client.position(token_1()).await?;
+
+        assert_eq!(position.token_id, token_1());
+        assert_eq!(position.size, dec!(15));
+        assert_eq!(position.avg_price, Some(dec!(0.45)));
+        first_page.assert();
+        second_page.assert();
+
+        // Buying 10 from -5 covers the short and opens 5 long at the buy price.
+        let mut covered = Position::new(token_1());
+        covered.apply_fill(Side::Sell, dec!(5), dec!(0.60));
+        covered.apply_fill(Side::Buy, dec!(10), dec!(0.50));
+        assert_eq!(covered.size, dec!(5));
+        assert_eq!(covered.avg_price, Some(dec!(0.50)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn export_trades_should_write_csv_rows_across_pages() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let first_page = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/data/trades")
+                .query_param("asset_id", token_1().to_string())
+                .query_param_missing("next_cursor")
+                .header(POLY_API_KEY, API_KEY.to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [trade("01", "TAKER", "BUY", "10", "0.40")],
+                "next_cursor": "MQ==",
+                "limit": 1,
+                "count": 1
+            }));
+        });
+        let second_page = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/data/trades")
+                .query_param("asset_id", token_1().to_string())
+                .query_param("next_cursor", "MQ==");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [trade("02", "TAKER", "SELL", "5", "0.60")],
+                "next_cursor": "LTE=",
+                "limit": 1,
+                "count": 1
+            }));
+        });
+
+        let filter = TradesRequest::builder().asset_id(token_1()).build();
+        let mut csv = Vec::new();
+        client
+            .export_trades(&filter, &mut csv, ExportFormat::Csv)
+            .await?;
+
+        let row = |id: &str, side: &str, price: &str, size: &str| {
+            format!(
+                "{id},2023-11-14T22:13:{}+00:00,\
+                 0x000000000000000000000000000000000000000000000000006d61726b657461,{},{side},\
+                 YES,{price},{size},0,CONFIRMED,TAKER,\
+                 0x0000000000000000000000000000000000000000000000000000000000000000",
+                20 + id.parse::<u32>().unwrap(),
+                token_1()
+            )
+        };
+        let expected = [
+            "id,match_time,market,asset_id,side,outcome,price,size,fee_rate_bps,status,\
+             trader_side,transaction_hash"
+                .to_owned(),
+            row("01", "BUY", "0.40", "10"),
+            row("02", "SELL", "0.60", "5"),
+        ];
+        assert_eq!(
+            String::from_utf8(csv)?.lines().collect::<Vec<_>>(),
+            expected
+        );
+        first_page.assert();
+        second_page.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sync_clock_should_cache_offset() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let server_time = chrono::Utc::now().timestamp() + 120;
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/time");
+            then.status(StatusCode::OK).json_body(server_time);
+        });
+
+        let offset = client.sync_clock().await?;
+
+        assert!((119..=120).contains(&offset), "unexpected offset {offset}");
+        assert_eq!(client.clock_offset(), Some(offset));
+        assert_eq!(client.timestamp_within_tolerance(AuthLayer::L2), Some(false));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_raw_order_should_sign_body() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let body = json!({
+            "order": { "salt": 1, "tokenId": "1" },
+            "orderType": "GTC",
+            "owner": API_KEY.to_string()
+        });
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/order")
+                .header(POLY_API_KEY, API_KEY.to_string())
+                .header(POLY_TIMESTAMP, TIMESTAMP)
+                .header(
+                    POLY_SIGNATURE,
+                    "H8R46-HWyLGZcCdh1pR5mO_hkBa-g81bC_YlsVhJB7Q=",
+                )
+                .json_body(body.clone());
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        let response = client
+            .post_raw_order(body, Some(TIMESTAMP.parse()?))
+            .await?;
+
+        assert!(response.success, "raw order should be accepted");
+        assert_eq!(response.status, OrderStatusType::Live);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_signed_order_with_body_should_return_sent_bytes() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let request = LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.45), dec!(10));
+        let signed = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await?;
+        let expected_body = serde_json::to_value(&signed)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/order")
+                .header(POLY_API_KEY, API_KEY.to_string())
+                .json_body(expected_body);
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        let (response, body) = client
+            .post_signed_order_with_body(&signed, Some(TIMESTAMP.parse()?))
+            .await?;
+
+        assert!(response.success, "order should be accepted");
+        assert_eq!(serde_json::from_slice::<SignedOrder>(&body)?, signed);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn data_host_should_serve_market_data_while_orders_use_host() -> anyhow::Result<()> {
+        let order_server = MockServer::start();
+        let data_server = MockServer::start();
+        let config = HotPathConfig::new(
+            Url::parse(&order_server.base_url())?,
+            POLYGON,
+            SecretString::from(PRIVATE_KEY.to_owned()),
+            SignatureType::Proxy,
+            FUNDER,
+            None,
+            fixed_policies(),
+        )?
+        .with_allow_insecure(true)
+        .with_data_host(Url::parse(&data_server.base_url())?);
+        let credentials = Credentials::new(API_KEY, SECRET.to_owned(), PASSPHRASE.to_owned());
+        let client = HotPathClient::with_credentials(config, credentials)?;
+
+        let midpoint_mock = data_server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/midpoint")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK)
+                .json_body(json!({ "mid": "0.45" }));
+        });
+        let order_mock = order_server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/order")
+                .header(POLY_API_KEY, API_KEY.to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        assert_eq!(client.get_midpoint(token_1()).await?, Some(dec!(0.45)));
+        let request = LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.45), dec!(10));
+        client.post_limit_order(&request).await?;
+
+        midpoint_mock.assert();
+        order_mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_signed_order_should_accept_externally_signed_order() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mut order = Order::default();
+        order.salt = U256::from(7);
+        order.maker = FUNDER;
+        order.signer = client.signer_address();
+        order.tokenId = token_1();
+        order.makerAmount = U256::from(4_500_000);
+        order.takerAmount = U256::from(10_000_000);
+        order.side = Side::Buy as u8;
+        order.signatureType = SignatureType::Proxy as u8;
+
+        let external = LocalSigner::from_str(PRIVATE_KEY)?;
+        let signature = external
+            .sign_hash(&client.order_signing_hash(&order, false))
+            .await?;
+        let signed = SignedOrder::builder()
+            .order(order)
+            .signature(signature)
+            .order_type(OrderType::GTC)
+            .owner(API_KEY)
+            .build();
+        let expected_body = serde_json::to_value(&signed)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/order")
+                .header(POLY_API_KEY, API_KEY.to_string())
+                .json_body(expected_body);
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        let response = client.post_signed_order(signed, None, None).await?;
+
+        assert!(
+            response.success,
+            "externally signed order should be accepted"
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_notifications_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/notifications")
+                .header(POLY_API_KEY, API_KEY.to_string())
+                .query_param("signature_type", (SignatureType::Proxy as u8).to_string());
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "type": 1,
+                    "owner": API_KEY,
+                    "payload": {
+                        "asset_id": token_1().to_string(),
+                        "condition_id": CONDITION_ID,
+                        "eventSlug": "btc-2025",
+                        "icon": "https://example.com/btc.png",
+                        "image": "https://example.com/btc.png",
+                        "market": CONDITION_ID,
+                        "market_slug": "btc-100k-2025",
+                        "matched_size": "20",
+                        "order_id": "0x2ae21876d2702d8b71308d0999062db9625a691ce4593c5f10230eeeff945e70",
+                        "original_size": "20",
+                        "outcome": "YES",
+                        "outcome_index": 0,
+                        "owner": API_KEY,
+                        "price": "0.12",
+                        "question": "Will BTC reach $100k in 2025?",
+                        "remaining_size": "0",
+                        "seriesSlug": "",
+                        "side": "buy",
+                        "trade_id": "565a5035-d70e-4493-9215-8cae52d26efe",
+                        "transaction_hash": "0x3bc57dcae83a930df64fce8fdc46a8fca9b98af92a7b83a8a2f2c657446c2a71",
+                        "type": ""
+                    }
+                },
+                {
+                    "type": 2,
+                    "owner": API_KEY,
+                    "payload": {
+                        "asset_id": token_2().to_string(),
+                        "condition_id": CONDITION_ID,
+                        "eventSlug": "btc-2025",
+                        "icon": "https://example.com/btc.png",
+                        "image": "https://example.com/btc.png",
+                        "market": CONDITION_ID,
+                        "market_slug": "btc-100k-2025",
+                        "matched_size": "5",
+                        "order_id": "0x3bc57dcae83a930df64fce8fdc46a8fca9b98af92a7b83a8a2f2c657446c2a71",
+                        "original_size": "10",
+                        "outcome": "NO",
+                        "outcome_index": 1,
+                        "owner": API_KEY,
+                        "price": "0.88",
+                        "question": "Will BTC reach $100k in 2025?",
+                        "remaining_size": "5",
+                        "seriesSlug": "",
+                        "side": "sell",
+                        "trade_id": "665a5035-d70e-4493-9215-8cae52d26efe",
+                        "transaction_hash": "0x2ae21876d2702d8b71308d0999062db9625a691ce4593c5f10230eeeff945e70",
+                        "type": ""
+                    }
+                }
+            ]));
+        });
+
+        let notifications = client.get_notifications().await?;
+
+        assert_eq!(notifications.len(), 2);
+        assert_eq!(notifications[0].r#type, 1);
+        assert_eq!(notifications[0].payload.asset_id, token_1());
+        assert_eq!(notifications[0].payload.matched_size, dec!(20));
+        assert_eq!(notifications[1].payload.side, Side::Sell);
+        assert_eq!(notifications[1].payload.remaining_size, dec!(5));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn mark_notifications_read_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::DELETE)
+                .path("/notifications")
+                .header(POLY_API_KEY, API_KEY.to_string())
+                .query_param("ids", "1,2");
+            then.status(StatusCode::OK);
+        });
+
+        client
+            .mark_notifications_read(&["1".to_owned(), "2".to_owned()])
+            .await?;
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn is_account_allowed_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/ban-status/closed-only")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY.to_string());
+            then.status(StatusCode::OK)
+                .json_body(json!({ "closed_only": false }));
+        });
+
+        assert!(
+            client.is_account_allowed().await?,
+            "account should be allowed"
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn is_account_allowed_should_report_closed_only() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/ban-status/closed-only");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "closed_only": true }));
+        });
+
+        assert!(
+            !client.is_account_allowed().await?,
+            "account should be blocked"
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn check_trading_ready_should_pass_when_funded_and_approved() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+        let exchange = client.exchange_for(false)?;
+        let neg_risk_exchange = client.exchange_for(true)?;
+
+        let ban_status = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/ban-status/closed-only")
+                .header(POLY_API_KEY, API_KEY.to_string());
+            then.status(StatusCode::OK)
+                .json_body(json!({ "closed_only": false }));
+        });
+        let balance = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/balance-allowance")
+                .query_param("asset_type", "COLLATERAL")
+                .query_param("signature_type", "1");
+            then.status(StatusCode::OK).json_body(json!({
+                "balance": "25000000",
+                "allowances": {
+                    exchange.to_string(): U256::MAX.to_string(),
+                    neg_risk_exchange.to_string(): "25000000",
+                }
+            }));
+        });
+
+        let readiness = client.check_trading_ready().await?;
+
+        assert!(readiness.credentials_valid, "credentials should be valid");
+        assert_eq!(readiness.balance, Some(dec!(25000000)));
+        assert!(
+            readiness.insufficient_allowances.is_empty(),
+            "allowances should cover the balance"
+        );
+        assert!(readiness.is_ready(), "account should be ready to trade");
+        ban_status.assert();
+        balance.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn check_trading_ready_should_report_missing_allowance() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+        let exchange = client.exchange_for(false)?;
+        let neg_risk_exchange = client.exchange_for(true)?;
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/ban-status/closed-only");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "closed_only": false }));
+        });
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/balance-allowance");
+            then.status(StatusCode::OK).json_body(json!({
+                "balance": "25000000",
+                "allowances": {
+                    exchange.to_string(): U256::MAX.to_string(),
+                    neg_risk_exchange.to_string(): "1000000",
+                }
+            }));
+        });
+
+        let readiness = client.check_trading_ready().await?;
+
+        assert!(readiness.credentials_valid, "credentials should be valid");
+        assert_eq!(readiness.insufficient_allowances, vec![neg_risk_exchange]);
+        assert!(
+            !readiness.is_ready(),
+            "account should not be ready to trade"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn check_trading_ready_should_report_rejected_credentials() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/ban-status/closed-only");
+            then.status(StatusCode::UNAUTHORIZED)
+                .json_body(json!({ "error": "Unauthorized/Invalid api key" }));
+        });
+        let balance = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/balance-allowance");
+            then.status(StatusCode::OK);
+        });
+
+        let readiness = client.check_trading_ready().await?;
+
+        assert!(
+            !readiness.credentials_valid,
+            "credentials should be rejected"
+        );
+        assert_eq!(readiness.balance, None);
+        assert!(
+            !readiness.is_ready(),
+            "account should not be ready to trade"
+        );
+        balance.assert_calls(0);
+
+        Ok(())
+    }
+
+    fn accepted_order(order_id: &str) -> serde_json::Value {
+        json!({
+            "error_msg": "",
+            "makingAmount": "",
+            "orderID": order_id,
+            "status": "live",
+            "success": true,
+            "takingAmount": ""
+        })
+    }
+
+    #[tokio::test]
+    async fn post_limit_order_should_not_retry() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client_with(&server.base_url(), |config| {
+            let retry = RetryPolicy::new(2, Duration::ZERO, Duration::ZERO);
+            config.with_read_retry(retry).with_write_retry(retry)
+        })?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/order");
+            then.status(StatusCode::SERVICE_UNAVAILABLE)
+                .json_body(json!({ "error": "service unavailable" }));
+        });
+
+        let request = LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.45), dec!(10));
+        let err = client.post_limit_order(&request).await.unwrap_err();
+
+        assert_eq!(err.status_code(), Some(503));
+        mock.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_limit_order_reliable_should_retry_with_write_retry() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client_with(&server.base_url(), |config| {
+            config.with_write_retry(RetryPolicy::new(2, Duration::ZERO, Duration::ZERO))
+        })?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/order");
+            then.status(StatusCode::SERVICE_UNAVAILABLE)
+                .json_body(json!({ "error": "service unavailable" }));
+        });
+
+        let request = LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.45), dec!(10));
+        client
+            .post_limit_order_reliable(&request)
+            .await
+            .unwrap_err();
+
+        mock.assert_calls(3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_limit_order_should_queue_beyond_in_flight_cap() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client_with(&server.base_url(), |config| {
+            config.with_max_in_flight_orders(NonZeroUsize::MIN)
+        })?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/order");
+            then.status(StatusCode::OK)
+                .delay(Duration::from_millis(100))
+                .json_body(accepted_order("0x01"));
+        });
+
+        let completed = Mutex::new(Vec::new());
+        let requests: Vec<_> = [dec!(10), dec!(20), dec!(30)]
+            .into_iter()
+            .map(|size| LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.45), size))
+            .collect();
+        let started = Instant::now();
+        let results = join_all(requests.iter().enumerate().map(|(index, request)| {
+            let client = client.clone();
+            let completed = &completed;
+            async move {
+                let result = client.post_limit_order(request).await;
+                completed.lock().unwrap().push(index);
+                result
+            }
+        }))
+        .await;
+
+        for result in results {
+            result?;
+        }
+        assert_eq!(*completed.lock().unwrap(), vec![0, 1, 2]);
+        assert!(
+            started.elapsed() >= Duration::from_millis(300),
+            "orders should be submitted one at a time"
+        );
+        mock.assert_calls(3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_limit_orders_should_share_in_flight_cap() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client_with(&server.base_url(), |config| {
+            config.with_max_in_flight_orders(NonZeroUsize::MIN)
+        })?;
+
+        let single = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/order");
+            then.status(StatusCode::OK)
+                .delay(Duration::from_millis(200))
+                .json_body(accepted_order("0x01"));
+        });
+        let batch = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/orders");
+            then.status(StatusCode::OK)
+                .delay(Duration::from_millis(200))
+                .json_body(json!([accepted_order("0x02")]));
+        });
+
+        let request = LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.45), dec!(10));
+        let started = Instant::now();
+        let (single_result, batch_result) = tokio::join!(
+            client.post_limit_order(&request),
+            client.post_limit_orders(std::slice::from_ref(&request))
+        );
+
+        single_result?;
+        for result in batch_result? {
+            result?;
+        }
+        assert!(
+            started.elapsed() >= Duration::from_millis(400),
+            "the batch should wait for the single order's slot"
+        );
+        single.assert();
+        batch.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_limit_order_should_time_out_waiting_for_in_flight_slot() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client_with(&server.base_url(), |config| {
+            config
+                .with_max_in_flight_orders(NonZeroUsize::MIN)
+                .with_order_permit_timeout(Duration::from_millis(50))
+        })?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/order");
+            then.status(StatusCode::OK)
+                .delay(Duration::from_millis(500))
+                .json_body(accepted_order("0x01"));
+        });
+
+        let request = LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.45), dec!(10));
+        let (first, second) = tokio::join!(
+            client.post_limit_order(&request),
+            client.post_limit_order(&request)
+        );
+
+        first?;
+        let err = second.unwrap_err();
+        assert_eq!(err.kind(), Kind::Timeout);
+        assert!(err.is_retryable(), "a queued order was never sent");
+        mock.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_signed_order_should_abort_after_deadline() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/order");
+            then.status(StatusCode::OK)
+                .delay(Duration::from_millis(500))
+                .json_body(accepted_order("0x01"));
+        });
+
+        let request = LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.45), dec!(10));
+        let signed = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await?;
+        let err = client
+            .post_signed_order(signed, None, Some(Duration::from_millis(50)))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Timeout);
+        assert!(!err.is_retryable(), "a missed deadline is not retryable");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_signed_order_should_succeed_within_deadline() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/order");
+            then.status(StatusCode::OK)
+                .json_body(accepted_order("0x01"));
+        });
+
+        let request = LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.45), dec!(10));
+        let signed = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await?;
+        let response = client
+            .post_signed_order(signed, None, Some(Duration::from_secs(5)))
+            .await?;
+
+        assert_eq!(response.order_id, "0x01");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_limit_order_should_surface_field_validation() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/order");
+            then.status(StatusCode::BAD_REQUEST).json_body(json!({
+                "error": "order 0x01 is invalid. Size (10) lower than the minimum: 15"
+            }));
+        });
+
+        let request = LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.45), dec!(10));
+        let err = client.post_limit_order(&request).await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::FieldValidation);
+        assert_eq!(err.status_code(), Some(400));
+        let field_validation = err
+            .downcast_ref::<FieldValidation>()
+            .expect("source should be FieldValidation");
+        assert_eq!(field_validation.field, "size");
+        assert_eq!(
+            field_validation.message,
+            "order 0x01 is invalid. Size (10) lower than the minimum: 15"
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_limit_orders_should_isolate_invalid_order() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/orders")
+                .header(POLY_API_KEY, API_KEY.to_string());
+            then.status(StatusCode::OK)
+                .json_body(json!([accepted_order("0x01"), accepted_order("0x02")]));
+        });
+
+        let requests = [
+            LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.45), dec!(10)),
+            LimitOrderRequest::new(token_1(), Side::Buy, dec!(1.50), dec!(10)),
+            LimitOrderRequest::new(token_2(), Side::Sell, dec!(0.55), dec!(10)),
+        ];
+        let results = client.post_limit_orders(&requests).await?;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().order_id, "0x01");
+        assert_eq!(results[1].as_ref().unwrap_err().kind(), Kind::Validation);
+        assert_eq!(results[2].as_ref().unwrap().order_id, "0x02");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_limit_orders_with_timestamp_should_apply_to_batch() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/orders")
+                .header(POLY_API_KEY, API_KEY.to_string())
+                .header(POLY_TIMESTAMP, TIMESTAMP);
+            then.status(StatusCode::OK)
+                .json_body(json!([accepted_order("0x01"), accepted_order("0x02")]));
+        });
+
+        let requests = [
+            LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.45), dec!(10)),
+            LimitOrderRequest::new(token_2(), Side::Sell, dec!(0.55), dec!(10)),
+        ];
+        let results = client
+            .post_limit_orders_with_timestamp(&requests, Some(TIMESTAMP.parse()?))
+            .await?;
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1].as_ref().unwrap().order_id, "0x02");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_limit_orders_should_isolate_malformed_response_entry() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/orders");
+            then.status(StatusCode::OK).json_body(json!([
+                accepted_order("0x01"),
+                { "success": false },
+                accepted_order("0x03")
+            ]));
+        });
+
+        let requests = [
+            LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.45), dec!(10)),
+            LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.46), dec!(10)),
+            LimitOrderRequest::new(token_2(), Side::Sell, dec!(0.55), dec!(10)),
+        ];
+        let results = client.post_limit_orders(&requests).await?;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().order_id, "0x01");
+        assert!(
+            results[1].is_err(),
+            "malformed entry should fail on its own"
+        );
+        assert_eq!(results[2].as_ref().unwrap().order_id, "0x03");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_limit_orders_should_split_batch_across_requests() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = HotPathConfig::new(
+            Url::parse(&server.base_url())?,
+            POLYGON,
+            SecretString::from(PRIVATE_KEY.to_owned()),
+            SignatureType::Proxy,
+            FUNDER,
+            None,
+            fixed_policies(),
+        )?
+        .with_allow_insecure(true)
+        .with_max_batch_size(2);
+        let credentials = Credentials::new(API_KEY, SECRET.to_owned(), PASSPHRASE.to_owned());
+        let client = HotPathClient::with_credentials(config, credentials)?;
+
+        // Each request is identified by the maker amount of its first order.
+        let batch = |first_maker_amount: &'static str, order_ids: &'static [&'static str]| {
+            server.mock(move |when, then| {
+                when.method(httpmock::Method::POST)
+                    .path("/orders")
+                    .header(POLY_API_KEY, API_KEY.to_string())
+                    .body_includes(format!(r#""makerAmount":"{first_maker_amount}""#));
+                then.status(StatusCode::OK).json_body(json!(
+                    order_ids
+                        .iter()
+                        .map(|id| accepted_order(id))
+                        .collect::<Vec<_>>()
+                ));
+            })
+        };
+        let first = batch("5000000", &["0x01", "0x02"]);
+        let second = batch("6000000", &["0x03", "0x04"]);
+        let third = batch("7000000", &["0x05"]);
+
+        let requests: Vec<_> = [dec!(10), dec!(11), dec!(12), dec!(13), dec!(14)]
+            .into_iter()
+            .map(|size| LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.50), size))
+            .collect();
+        let results = client.post_limit_orders(&requests).await?;
+
+        let order_ids: Vec<_> = results
+            .iter()
+            .map(|result| result.as_ref().unwrap().order_id.as_str())
+            .collect();
+        assert_eq!(order_ids, ["0x01", "0x02", "0x03", "0x04", "0x05"]);
+        first.assert();
+        second.assert();
+        third.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn bootstrap_should_signal_derive_fallback() -> anyhow::Result<()> {
+        let server = MockServer::start();
+
+        let create = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/auth/api-key");
+            then.status(StatusCode::BAD_REQUEST)
+                .json_body(json!({ "error": "api key already exists" }));
+        });
+        let derive = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/derive-api-key");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+
+        let config = HotPathConfig::new(
+            Url::parse(&server.base_url())?,
+            POLYGON,
+            SecretString::from(PRIVATE_KEY.to_owned()),
+            SignatureType::Proxy,
+            FUNDER,
+            None,
+            fixed_policies(),
+        )?
+        .with_allow_insecure(true);
+        let client = HotPathClient::bootstrap(config).await?;
+
+        assert_eq!(client.credentials_origin(), CredentialsOrigin::Derived);
+        assert_eq!(client.credentials().key(), API_KEY);
+        create.assert();
+        derive.assert();
+
+        Ok(())
+    }
+
+    fn bootstrap_config(
+        server: &MockServer,
+        mode: CredentialMode,
+    ) -> anyhow::Result<HotPathConfig> {
+        Ok(HotPathConfig::new(
+            Url::parse(&server.base_url())?,
+            POLYGON,
+            SecretString::from(PRIVATE_KEY.to_owned()),
+            SignatureType::Proxy,
+            FUNDER,
+            None,
+            fixed_policies(),
+        )?
+        .with_allow_insecure(true)
+        .with_credential_mode(mode))
+    }
+
+    fn credentials_body() -> serde_json::Value {
+        json!({
+            "apiKey": API_KEY.to_string(),
+            "passphrase": PASSPHRASE,
+            "secret": SECRET
+        })
+    }
+
+    fn mock_funder_code<'server>(
+        server: &'server MockServer,
+        code: &str,
+    ) -> httpmock::Mock<'server> {
+        server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/rpc")
+                .body_includes("eth_getCode");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "jsonrpc": "2.0", "id": 1, "result": code }));
+        })
+    }
+
+    #[tokio::test]
+    async fn bootstrap_should_accept_contract_funder() -> anyhow::Result<()> {
+        let server = MockServer::start();
+
+        let rpc = mock_funder_code(&server, "0x6080604052");
+        let create = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/auth/api-key");
+            then.status(StatusCode::OK).json_body(credentials_body());
+        });
+
+        let config = bootstrap_config(&server, CredentialMode::CreateOrDerive)?
+            .with_funder_rpc_url(Url::parse(&server.url("/rpc"))?);
+        HotPathClient::bootstrap(config).await?;
+
+        rpc.assert();
+        create.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn bootstrap_should_reject_funder_without_code() -> anyhow::Result<()> {
+        let server = MockServer::start();
+
+        let rpc = mock_funder_code(&server, "0x");
+        let create = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/auth/api-key");
+            then.status(StatusCode::OK).json_body(credentials_body());
+        });
+
+        let config = bootstrap_config(&server, CredentialMode::CreateOrDerive)?
+            .with_funder_rpc_url(Url::parse(&server.url("/rpc"))?);
+        let err = HotPathClient::bootstrap(config).await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Validation);
+        assert!(
+            err.to_string().contains("has no contract code"),
+            "unexpected error: {err}"
+        );
+        rpc.assert();
+        create.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn bootstrap_should_sign_l1_headers_with_server_time() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let server_time = 1_000_000_i64;
+
+        let time = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/time");
+            then.status(StatusCode::OK).json_body(server_time);
+        });
+        let create = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/auth/api-key")
+                .header(POLY_TIMESTAMP, server_time.to_string());
+            then.status(StatusCode::OK).json_body(credentials_body());
+        });
+
+        let policies = HotPathPolicies::builder()
+            .fixed_tick_size(TickSize::Hundredth)
+            .fixed_neg_risk(false)
+            .fixed_fee_rate_bps(0)
+            .time(TimePolicy::FetchAndCache)
+            .build()?;
+        let mut config = bootstrap_config(&server, CredentialMode::CreateOnly)?;
+        config.policies = policies;
+        HotPathClient::bootstrap(config).await?;
+
+        time.assert();
+        create.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn bootstrap_create_only_should_create_key() -> anyhow::Result<()> {
+        let server = MockServer::start();
+
+        let create = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/auth/api-key");
+            then.status(StatusCode::OK).json_body(credentials_body());
+        });
+        let derive = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/derive-api-key");
+            then.status(StatusCode::OK).json_body(credentials_body());
+        });
+
+        let config = bootstrap_config(&server, CredentialMode::CreateOnly)?;
+        let client = HotPathClient::bootstrap(config).await?;
+
+        assert_eq!(client.credentials_origin(), CredentialsOrigin::Created);
+        create.assert();
+        derive.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn bootstrap_create_only_should_fail_on_existing_key() -> anyhow::Result<()> {
+        let server = MockServer::start();
+
+        let create = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/auth/api-key");
+            then.status(StatusCode::BAD_REQUEST)
+                .json_body(json!({ "error": "api key already exists" }));
+        });
+        let derive = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/derive-api-key");
+            then.status(StatusCode::OK).json_body(credentials_body());
+        });
+
+        let config = bootstrap_config(&server, CredentialMode::CreateOnly)?;
+        let err = HotPathClient::bootstrap(config).await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Status);
+        assert_eq!(err.status_code(), Some(400));
+        create.assert();
+        derive.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn bootstrap_derive_only_should_not_create_key() -> anyhow::Result<()> {
+        let server = MockServer::start();
+
+        let create = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/auth/api-key");
+            then.status(StatusCode::OK).json_body(credentials_body());
+        });
+        let derive = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/derive-api-key");
+            then.status(StatusCode::OK).json_body(credentials_body());
+        });
+
+        let config = bootstrap_config(&server, CredentialMode::DeriveOnly)?;
+        let client = HotPathClient::bootstrap(config).await?;
+
+        assert_eq!(client.credentials_origin(), CredentialsOrigin::Derived);
+        assert_eq!(client.credentials().key(), API_KEY);
+        create.assert_calls(0);
+        derive.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn bootstrap_derive_only_should_fail_without_existing_key() -> anyhow::Result<()> {
+        let server = MockServer::start();
+
+        let create = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/auth/api-key");
+            then.status(StatusCode::OK).json_body(credentials_body());
+        });
+        let derive = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/derive-api-key");
+            then.status(StatusCode::NOT_FOUND)
+                .json_body(json!({ "error": "api key not found" }));
+        });
+
+        let config = bootstrap_config(&server, CredentialMode::DeriveOnly)?;
+        let err = HotPathClient::bootstrap(config).await.unwrap_err();
+
+        assert_eq!(err.status_code(), Some(404));
+        create.assert_calls(0);
+        derive.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn bootstrap_should_sign_orders_with_derived_nonce_when_enabled() -> anyhow::Result<()> {
+        for (use_derived_nonce, order_nonce) in [(false, "0"), (true, "7")] {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(httpmock::Method::POST).path("/auth/api-key");
+                then.status(StatusCode::BAD_REQUEST)
+                    .json_body(json!({ "error": "api key already exists" }));
+            });
+            server.mock(|when, then| {
+                when.method(httpmock::Method::GET)
+                    .path("/auth/derive-api-key");
+                then.status(StatusCode::OK).json_body(json!({
+                    "apiKey": API_KEY.to_string(),
+                    "passphrase": PASSPHRASE,
+                    "secret": SECRET,
+                    "nonce": "7"
+                }));
+            });
+            let post = server.mock(|when, then| {
+                when.method(httpmock::Method::POST)
+                    .path("/order")
+                    .body_includes(format!(r#""nonce":"{order_nonce}""#));
+                then.status(StatusCode::OK)
+                    .json_body(accepted_order("0x01"));
+            });
+
+            let config = HotPathConfig::new(
+                Url::parse(&server.base_url())?,
+                POLYGON,
+                SecretString::from(PRIVATE_KEY.to_owned()),
+                SignatureType::Proxy,
+                FUNDER,
+                None,
+                fixed_policies(),
+            )?
+            .with_allow_insecure(true)
+            .with_derived_nonce(use_derived_nonce);
+            let client = HotPathClient::bootstrap(config).await?;
+
+            assert_eq!(client.derived_nonce(), Some(7));
+            let request = LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.45), dec!(10));
+            client.post_limit_order(&request).await?;
+            post.assert();
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn refresh_credentials_with_nonce_should_sign_nonce() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/auth/api-key")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_NONCE, "7");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": Uuid::max().to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+
+        let credentials = client.refresh_credentials_with_nonce(7).await?;
+
+        assert_eq!(credentials.key(), Uuid::max());
+        assert_eq!(client.credentials_origin(), CredentialsOrigin::Created);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_limit_order_should_surface_delayed_status() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "errorMsg": "",
+                "makingAmount": "",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "delayed",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        let request = LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.45), dec!(10));
+        let response = client.post_limit_order(&request).await?;
+
+        assert!(response.success, "delayed orders are accepted");
+        assert_eq!(response.status, OrderStatusType::Delayed);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_order_by_hash_should_cancel_matching_order_id() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let request = LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.45), dec!(10));
+        let signed = client
+            .sign_limit_order(&request, LimitOrderOverrides::default())
+            .await?;
+        let hash = client.order_signing_hash(&signed.order, false);
+        let order_id = hash.to_string();
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::DELETE)
+                .path("/order")
+                .header(POLY_API_KEY, API_KEY.to_string())
+                .json_body(json!({ "orderId": order_id }));
+            then.status(StatusCode::OK).json_body(json!({
+                "canceled": [order_id],
+                "notCanceled": {}
+            }));
+        });
+
+        let response = client.cancel_order_by_hash(hash).await?;
+
+        assert_eq!(response.canceled, vec![order_id]);
+        mock.assert();
+
+        Ok(())
+    }
+
+    fn resting_order(id: &str, side: &str, size_matched: &str) -> OpenOrderResponse {
+        let mut order = open_order("LIVE", size_matched);
+        order["id"] = json!(id);
+        order["side"] = json!(side);
+        serde_json::from_value(order).unwrap()
+    }
+
+    #[tokio::test]
+    async fn cancel_orders_with_freed_collateral_should_estimate_from_orders() -> anyhow::Result<()>
+    {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::DELETE)
+                .path("/orders")
+                .json_body(json!(["o1", "o2", "o3"]));
+            then.status(StatusCode::OK).json_body(json!({
+                "canceled": ["o1", "o2"],
+                "notCanceled": { "o3": "order already matched" }
+            }));
+        });
+
+        let orders = [
+            resting_order("o1", "BUY", "4"),
+            resting_order("o2", "SELL", "0"),
+            resting_order("o3", "BUY", "0"),
+        ];
+        let response = client
+            .cancel_orders_with_freed_collateral(Some(&orders))
+            .await?;
+
+        // Only the unfilled 6 shares of o1 at 0.45 locked collateral.
+        assert_eq!(response.freed_collateral, Some(dec!(2.70)));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_orders_with_freed_collateral_should_prefer_server_amount() -> anyhow::Result<()>
+    {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::DELETE).path("/orders");
+            then.status(StatusCode::OK).json_body(json!({
+                "canceled": ["o1"],
+                "notCanceled": {},
+                "freedCollateral": "4.5"
+            }));
+        });
+
+        let orders = [resting_order("o1", "BUY", "4")];
+        let response = client
+            .cancel_orders_with_freed_collateral(Some(&orders))
+            .await?;
+
+        assert_eq!(response.freed_collateral, Some(dec!(4.5)));
+        mock.assert();
+
+        Ok(())
+    }
+
+    async fn tracking_client_with_resting_order(
+        server: &MockServer,
+    ) -> anyhow::Result<HotPathClient> {
+        let client = create_client_with(&server.base_url(), |config| {
+            config.with_order_tracking(true)
+        })?;
+        let mut post = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/order");
+            then.status(StatusCode::OK)
+                .json_body(accepted_order("0x01"));
+        });
+
+        let request = LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.45), dec!(10));
+        client.post_limit_order(&request).await?;
+        post.assert();
+        post.delete();
+
+        assert_eq!(
+            client.tracked_orders().keys().collect::<Vec<_>>(),
+            ["0x01"],
+            "accepted order should be tracked"
+        );
+        Ok(client)
+    }
+
+    #[tokio::test]
+    async fn cancel_orders_with_freed_collateral_should_estimate_from_tracked_orders()
+    -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = tracking_client_with_resting_order(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::DELETE)
+                .path("/orders")
+                .json_body(json!(["0x01"]));
+            then.status(StatusCode::OK)
+                .json_body(json!({ "canceled": ["0x01"], "notCanceled": {} }));
+        });
+
+        let response = client.cancel_orders_with_freed_collateral(None).await?;
+
+        // The tracked BUY of 10 shares at 0.45, fills unobserved.
+        assert_eq!(response.freed_collateral, Some(dec!(4.50)));
+        assert!(
+            client.tracked_orders().is_empty(),
+            "canceled order should stay untracked"
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_orders_with_freed_collateral_without_tracking_should_fail() -> anyhow::Result<()>
+    {
+        let server = MockServer::start();
+        let client = create_client(&server)?;
+
+        let err = client
+            .cancel_orders_with_freed_collateral(None)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Validation);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_order_should_keep_tracked_order_removed_on_success() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = tracking_client_with_resting_order(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::DELETE).path("/order");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "canceled": ["0x01"], "notCanceled": {} }));
+        });
+
+        client.cancel_order("0x01").await?;
+
+        assert!(
+            client.tracked_orders().is_empty(),
+            "canceled order should stay untracked"
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn replace_order_should_cancel_and_repost_size_only_reduction() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = tracking_client_with_resting_order(&server).await?;
+
+        let cancel = server.mock(|when, then| {
+            when.method(httpmock::Method::DELETE)
+                .path("/order")
+                .json_body(json!({ "orderId": "0x01" }));
+            then.status(StatusCode::OK)
+                .json_body(json!({ "canceled": ["0x01"], "notCanceled": {} }));
+        });
+        let post = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/order");
+            then.status(StatusCode::OK)
+                .json_body(accepted_order("0x02"));
+        });
+
+        let request = LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.45), dec!(5));
+        let response = client.replace_order("0x01", &request).await?;
+
+        assert_eq!(response.order_id, "0x02");
+        let tracked = client.tracked_orders();
+        assert_eq!(tracked.keys().collect::<Vec<_>>(), ["0x02"]);
+        assert_eq!(tracked["0x02"].size, dec!(5));
+        cancel.assert();
+        post.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn replace_order_should_not_post_when_cancel_is_refused() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = tracking_client_with_resting_order(&server).await?;
+
+        let cancel = server.mock(|when, then| {
+            when.method(httpmock::Method::DELETE).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "canceled": [],
+                "notCanceled": { "0x01": "order is matched" }
+            }));
+        });
+        let post = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/order");
+            then.status(StatusCode::OK)
+                .json_body(accepted_order("0x02"));
+        });
+
+        let request = LimitOrderRequest::new(token_1(), Side::Buy, dec!(0.45), dec!(5));
+        let err = client.replace_order("0x01", &request).await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::CancelRejected);
+        let rejected = err.downcast_ref::<CancelRejected>().unwrap();
+        assert_eq!(rejected.order_id, "0x01");
+        assert_eq!(rejected.reason, "order is matched");
+        cancel.assert();
+        post.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_order_should_restore_tracked_order_on_failure() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = tracking_client_with_resting_order(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::DELETE).path("/order");
+            then.status(StatusCode::INTERNAL_SERVER_ERROR)
+                .json_body(json!({ "error": "internal error" }));
+        });
+
+        let err = client.cancel_order("0x01").await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Status);
+        let tracked = client.tracked_orders();
+        assert_eq!(tracked.len(), 1);
+        assert_eq!(tracked["0x01"].price, dec!(0.45));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_order_should_restore_tracked_order_not_canceled() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = tracking_client_with_resting_order(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::DELETE).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "canceled": [],
+                "notCanceled": { "0x01": "order can't be canceled" }
+            }));
+        });
+
+        client.cancel_order("0x01").await?;
+
+        assert!(
+            client.tracked_orders().contains_key("0x01"),
+            "order the exchange kept should be tracked again"
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_all_should_cancel_in_chunks_and_report_failed_chunk() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = HotPathConfig::new(
+            Url::parse(&server.base_url())?,
+            POLYGON,
+            SecretString::from(PRIVATE_KEY.to_owned()),
+            SignatureType::Proxy,
+            FUNDER,
+            None,
+            fixed_policies(),
+        )?
+        .with_allow_insecure(true)
+        .with_cancel_chunk_size(2)
+        .with_cancel_retry(RetryPolicy::new(2, Duration::ZERO, Duration::ZERO));
+        let credentials = Credentials::new(API_KEY, SECRET.to_owned(), PASSPHRASE.to_owned());
+        let client = HotPathClient::with_credentials(config, credentials)?;
+
+        let orders: Vec<_> = ["o1", "o2", "o3", "o4", "o5"]
+            .into_iter()
+            .map(|id| {
+                let mut order = open_order("LIVE", "0");
+                order["id"] = json!(id);
+                order
+            })
+            .collect();
+        let list = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/data/orders")
+                .header(POLY_API_KEY, API_KEY.to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "data": orders,
+                "next_cursor": "LTE=",
+                "limit": 500,
+                "count": 5
+            }));
+        });
+        let chunk = |ids: &'static [&'static str], status: StatusCode| {
+            server.mock(move |when, then| {
+                when.method(httpmock::Method::DELETE)
+                    .path("/orders")
+                    .json_body(json!(ids));
+                if status == StatusCode::OK {
+                    then.status(status)
+                        .json_body(json!({ "canceled": ids, "notCanceled": {} }));
+                } else {
+                    then.status(status)
+                        .json_body(json!({ "error": "service unavailable" }));
+                }
+            })
+        };
+        let first = chunk(&["o1", "o2"], StatusCode::OK);
+        let failing = chunk(&["o3", "o4"], StatusCode::SERVICE_UNAVAILABLE);
+        let last = chunk(&["o5"], StatusCode::OK);
+
+        let response = client.cancel_all().await?;
+
+        assert_eq!(response.canceled, vec!["o1", "o2", "o5"]);
+        assert_eq!(response.not_canceled.len(), 2);
+        assert!(
+            response.not_canceled["o3"].contains("service unavailable"),
+            "unexpected reason: {}",
+            response.not_canceled["o3"]
+        );
+        list.assert();
+        first.assert();
+        failing.assert_calls(3);
+        last.assert();
+
+        Ok(())
+    }
+}
+
+mod mock {
+    use chrono::Utc;
+    use polymarket_client_sdk::clob::types::response::{
+        OrderBookSummaryResponse, OrderSummary, PostOrderResponse,
+    };
+    use polymarket_client_sdk::clob::types::{OrderStatusType, Side};
+    use polymarket_client_sdk::error::Error;
+    use polymarket_client_sdk::hotpath::{
+        ClobMarketData, ClobOrders, LimitOrderRequest, MockCall, MockClobClient, MockMarketData,
+    };
+    use polymarket_client_sdk::types::{B256, Decimal, U256};
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    /// Quotes both sides of `token_id` around `mid`, cancelling the bid if the ask is
+    /// rejected.
+    async fn quote<C: ClobOrders>(
+        client: &C,
+        token_id: U256,
+        mid: Decimal,
+    ) -> polymarket_client_sdk::Result<()> {
+        let bid = LimitOrderRequest::new(token_id, Side::Buy, mid - dec!(0.01), dec!(10));
+        let ask = LimitOrderRequest::new(token_id, Side::Sell, mid + dec!(0.01), dec!(10));
+
+        let bid = client.post_limit_order(&bid).await?;
+        if client.post_limit_order(&ask).await.is_err() {
+            client.cancel_order(&bid.order_id).await?;
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn strategy_should_submit_expected_orders() -> anyhow::Result<()> {
+        let client = MockClobClient::new();
+
+        quote(&client, token_1(), dec!(0.50)).await?;
+
+        let orders = client.posted_orders();
+        assert_eq!(orders.len(), 2);
+        assert_eq!(orders[0].side, Side::Buy);
+        assert_eq!(orders[0].price, dec!(0.49));
+        assert_eq!(orders[1].side, Side::Sell);
+        assert_eq!(orders[1].price, dec!(0.51));
+        assert_eq!(client.calls().len(), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn strategy_should_cancel_bid_on_rejected_ask() -> anyhow::Result<()> {
+        let client = MockClobClient::new();
+        client.push_post_response(Ok(PostOrderResponse::builder()
+            .making_amount(dec!(4.9))
+            .taking_amount(dec!(10))
+            .order_id("bid-1")
+            .status(OrderStatusType::Live)
+            .success(true)
+            .build()));
+        client.push_post_response(Err(Error::validation("not enough balance")));
+
+        quote(&client, token_1(), dec!(0.50)).await?;
+
+        let calls = client.calls();
+        assert_eq!(calls.len(), 3);
+        assert!(
+            matches!(&calls[2], MockCall::CancelOrder { order_id } if order_id == "bid-1"),
+            "unexpected call: {:?}",
+            calls[2]
+        );
+
+        Ok(())
+    }
+
+    fn book(token_id: U256, bids: &[Decimal], asks: &[Decimal]) -> OrderBookSummaryResponse {
+        let levels = |prices: &[Decimal]| {
+            prices
+                .iter()
+                .map(|&price| OrderSummary::builder().price(price).size(dec!(100)).build())
+                .collect::<Vec<_>>()
+        };
+
+        OrderBookSummaryResponse::builder()
+            .market(B256::ZERO)
+            .asset_id(token_id)
+            .timestamp(Utc::now())
+            .bids(levels(bids))
+            .asks(levels(asks))
+            .min_order_size(dec!(5))
+            .neg_risk(false)
+            .tick_size(TickSize::Hundredth)
+            .build()
+    }
+
+    /// Quotes around the book midpoint, or skips the token when the book is one-sided.
+    async fn quote_from_book<M: ClobMarketData, C: ClobOrders>(
+        market_data: &M,
+        orders: &C,
+        token_id: U256,
+    ) -> polymarket_client_sdk::Result<()> {
+        match market_data.get_midpoint(token_id).await? {
+            Some(mid) => quote(orders, token_id, mid).await,
+            None => Ok(()),
+        }
+    }
+
+    #[tokio::test]
+    async fn strategy_should_react_to_injected_book_state() -> anyhow::Result<()> {
+        let market_data = MockMarketData::new();
+        let orders = MockClobClient::new();
+
+        market_data.set_order_book(book(token_1(), &[dec!(0.40), dec!(0.42)], &[dec!(0.46)]));
+        quote_from_book(&market_data, &orders, token_1()).await?;
+
+        let posted = orders.posted_orders();
+        assert_eq!(posted.len(), 2);
+        assert_eq!(posted[0].price, dec!(0.43));
+        assert_eq!(posted[1].price, dec!(0.45));
+
+        market_data.set_order_book(book(token_1(), &[dec!(0.42)], &[]));
+        quote_from_book(&market_data, &orders, token_1()).await?;
+
+        assert_eq!(
+            orders.posted_orders().len(),
+            2,
+            "one-sided book should not be quoted"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn mock_market_data_should_serve_injected_books() -> anyhow::Result<()> {
+        let market_data = MockMarketData::new();
+        market_data.set_order_book(book(
+            token_1(),
+            &[dec!(0.40), dec!(0.42), dec!(0.41)],
+            &[dec!(0.47), dec!(0.46)],
+        ));
+        market_data.set_last_trade_price(token_1(), dec!(0.44));
+
+        let bbo = market_data.get_bbo(token_1()).await?;
+        assert_eq!(bbo.best_bid, Some(dec!(0.42)));
+        assert_eq!(bbo.best_ask, Some(dec!(0.46)));
+        assert_eq!(market_data.get_midpoint(token_1()).await?, Some(dec!(0.44)));
+        assert_eq!(
+            market_data.get_last_trade_price(token_1()).await?,
+            Some(dec!(0.44))
+        );
+
+        let top = market_data
+            .get_order_book(token_1(), Some(1))
+            .await?
+            .expect("book should be injected");
+        assert_eq!(top.bids.len(), 1);
+        assert_eq!(top.bids[0].price, dec!(0.42));
+
+        market_data.remove_order_book(token_1());
+        assert_eq!(market_data.get_order_book(token_1(), None).await?, None);
+        assert_eq!(market_data.get_midpoint(token_2()).await?, None);
+
+        Ok(())
+    }
+}
@@ -1767,3 +1767,97 @@ mod message_parsing {
         assert_eq!(ltp.timestamp, 1_750_428_146_322);
     }
 }
+
+mod hotpath {
+    use httpmock::MockServer;
+    use polymarket_client_sdk::POLYGON;
+    use polymarket_client_sdk::auth::{Credentials, SecretString};
+    use polymarket_client_sdk::clob::types::{Side, SignatureType, TickSize};
+    use polymarket_client_sdk::hotpath::{
+        HotPathClient, HotPathConfig, HotPathPolicies, LimitOrderRequest,
+    };
+    use polymarket_client_sdk::types::address;
+    use reqwest::StatusCode;
+    use rust_decimal_macros::dec;
+    use tokio::time::sleep;
+    use url::Url;
+
+    use super::*;
+    use crate::common::{API_KEY, PASSPHRASE, PRIVATE_KEY, SECRET};
+
+    const TAKER_ORDER_ID: &str =
+        "0x06bc63e346ed4ceddce9efd6b3af37c8f8f440c92fe7da6b2d0f9e4ccbc50c42";
+
+    fn credentials() -> Credentials {
+        Credentials::new(API_KEY, SECRET.to_owned(), PASSPHRASE.to_owned())
+    }
+
+    fn hotpath_client(server: &MockServer) -> HotPathClient {
+        let config = HotPathConfig::new(
+            Url::parse(&server.base_url()).unwrap(),
+            POLYGON,
+            SecretString::from(PRIVATE_KEY.to_owned()),
+            SignatureType::Proxy,
+            address!("0x995c9b1f779c04e65ac6ab2fd8a6e5e2e8e6a7f1"),
+            None,
+            HotPathPolicies::fixed(TickSize::Hundredth, false, 0),
+        )
+        .unwrap()
+        .with_allow_insecure(true);
+
+        HotPathClient::with_credentials(config, credentials()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn post_and_watch_yields_fills_for_the_posted_order() {
+        let mut ws_server = MockWsServer::start().await;
+        let http_server = MockServer::start();
+        let client = hotpath_client(&http_server);
+        let ws = Client::new(&format!("ws://{}", ws_server.addr), Config::default())
+            .unwrap()
+            .authenticate(credentials(), client.address())
+            .unwrap();
+
+        // Wait for connections to establish
+        sleep(Duration::from_millis(100)).await;
+
+        let post = http_server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": TAKER_ORDER_ID,
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        let request = LimitOrderRequest::new(payloads::asset_id(), Side::Buy, dec!(0.57), dec!(10));
+        let (response, updates) = client
+            .post_and_watch(&ws, payloads::MARKET, &request)
+            .await
+            .unwrap();
+        let mut updates = Box::pin(updates);
+
+        assert_eq!(response.order_id, TAKER_ORDER_ID);
+        post.assert();
+
+        let sub_request = ws_server.recv_subscription().await.unwrap();
+        assert!(sub_request.contains("\"type\":\"user\""));
+
+        // An update for another order is filtered out; the fill of ours comes through.
+        ws_server.send(&payloads::order().to_string());
+        ws_server.send(&payloads::trade().to_string());
+
+        let result = timeout(Duration::from_secs(2), updates.next()).await;
+        match result.unwrap().unwrap().unwrap() {
+            WsMessage::Trade(trade) => {
+                assert_eq!(trade.taker_order_id.as_deref(), Some(TAKER_ORDER_ID));
+                assert_eq!(trade.size, dec!(10));
+                assert_eq!(trade.price, dec!(0.57));
+            }
+            other => panic!("Expected Trade, got {other:?}"),
+        }
+    }
+}